@@ -0,0 +1,512 @@
+//! `hackathonia` command-line tool for hackathon organizers and signers.
+
+mod output;
+mod report;
+mod spec;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use hackathonia_client::hackathon_escrow::{
+    AccountingSummary, LedgerEntry, MultisigChangeType, PayoutRequestWithContext, UserRole, MAX_PAGE_SIZE,
+};
+use hackathonia_client::ink_e2e::subxt_signer::SecretUri;
+use hackathonia_client::ink_e2e::Keypair;
+use hackathonia_client::{parse_address, Balance, EscrowClient};
+use ink::primitives::Address;
+use serde::Serialize;
+
+use output::{Format, PendingRow, StatsView, Table};
+use report::{Statement, StatementFormat};
+use spec::HackathonSpec;
+
+#[derive(Parser)]
+#[command(name = "hackathonia", version, about = "Manage hackathon escrows from the command line")]
+struct Cli {
+    /// WebSocket URL of the node
+    #[arg(long, env = "HACKATHONIA_URL", default_value = "ws://127.0.0.1:9944", global = true)]
+    url: String,
+    /// Address of the deployed escrow contract
+    #[arg(long, env = "HACKATHONIA_CONTRACT", value_parser = parse_address, global = true)]
+    contract: Option<Address>,
+    /// Secret URI of the signing account, e.g. a mnemonic, or `//Alice` on a dev node
+    #[arg(long, env = "HACKATHONIA_SURI", global = true, hide_env_values = true)]
+    suri: Option<String>,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Table, global = true)]
+    output: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a hackathon described in a TOML file
+    Create { file: PathBuf },
+    /// Deposit native funds into a hackathon escrow (only treasurers, once the hackathon grants one)
+    Deposit { hackathon_id: u32, amount: Balance },
+    /// List pending payout requests for the signing account, or for one hackathon
+    Pending {
+        #[arg(long)]
+        hackathon: Option<u32>,
+    },
+    /// Sign one payout request, or batch-sign several
+    Sign {
+        #[arg(required = true)]
+        request_ids: Vec<u32>,
+    },
+    /// Propose a multisig change for a hackathon
+    Propose {
+        hackathon_id: u32,
+        #[command(subcommand)]
+        change: Change,
+    },
+    /// Execute an approved multisig change once its time lock has passed
+    Execute { change_id: u32 },
+    /// Veto a scheduled multisig change; it is cancelled once enough signer weight vetoes it
+    Cancel { change_id: u32 },
+    /// Print deposit, payout and request statistics of a hackathon
+    Stats { hackathon_id: u32 },
+    /// Export the accounting statement of a hackathon, optionally for one period
+    Report {
+        hackathon_id: u32,
+        /// Start of the period as a block timestamp, inclusive
+        #[arg(long, default_value_t = 0)]
+        from: u64,
+        /// End of the period as a block timestamp, exclusive; defaults to now
+        #[arg(long)]
+        to: Option<u64>,
+        /// Statement file format
+        #[arg(long, value_enum, default_value_t = StatementFormat::Csv)]
+        format: StatementFormat,
+    },
+}
+
+#[derive(Subcommand)]
+enum Change {
+    /// Add a signer
+    AddSigner {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+    },
+    /// Remove a signer
+    RemoveSigner {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+    },
+    /// Replace one signer with another
+    ReplaceSigner {
+        #[arg(value_parser = parse_address)]
+        old: Address,
+        #[arg(value_parser = parse_address)]
+        new: Address,
+    },
+    /// Change the number of signatures required
+    Threshold { required_signatures: u32 },
+    /// Hand the hackathon over to another organizer
+    TransferOrganizer {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+    },
+    /// Set how long approved changes wait before they can be executed
+    Delay {
+        /// Delay in milliseconds of block time
+        delay: u64,
+    },
+    /// Change the weight of a signer
+    Weight {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+        weight: u32,
+    },
+    /// Grant a role to an address
+    GrantRole {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+        #[arg(value_enum)]
+        role: Role,
+    },
+    /// Revoke a role from an address
+    RevokeRole {
+        #[arg(value_parser = parse_address)]
+        address: Address,
+        #[arg(value_enum)]
+        role: Role,
+    },
+}
+
+/// Roles that multisig changes can grant or revoke
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Role {
+    Proposer,
+    Approver,
+    Treasurer,
+    Editor,
+    Auditor,
+}
+
+impl From<Role> for UserRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Proposer => UserRole::PayoutProposer,
+            Role::Approver => UserRole::PayoutApprover,
+            Role::Treasurer => UserRole::Treasurer,
+            Role::Editor => UserRole::MetadataEditor,
+            Role::Auditor => UserRole::Auditor,
+        }
+    }
+}
+
+/// Arguments of `request_multisig_change` other than the hackathon and change type
+#[derive(Default)]
+struct ChangeFields {
+    old_address: Option<Address>,
+    new_address: Option<Address>,
+    new_threshold: Option<u32>,
+    new_organizer: Option<Address>,
+    new_delay: Option<u64>,
+    new_weight: Option<u32>,
+    role: Option<UserRole>,
+}
+
+impl Change {
+    fn into_request(self) -> (MultisigChangeType, ChangeFields) {
+        let fields = ChangeFields::default();
+        match self {
+            Change::AddSigner { address } => {
+                (MultisigChangeType::AddSigner, ChangeFields { new_address: Some(address), ..fields })
+            }
+            Change::RemoveSigner { address } => {
+                (MultisigChangeType::RemoveSigner, ChangeFields { old_address: Some(address), ..fields })
+            }
+            Change::ReplaceSigner { old, new } => (
+                MultisigChangeType::ReplaceSigner,
+                ChangeFields { old_address: Some(old), new_address: Some(new), ..fields },
+            ),
+            Change::Threshold { required_signatures } => (
+                MultisigChangeType::UpdateThreshold,
+                ChangeFields { new_threshold: Some(required_signatures), ..fields },
+            ),
+            Change::TransferOrganizer { address } => {
+                (MultisigChangeType::TransferOrganizer, ChangeFields { new_organizer: Some(address), ..fields })
+            }
+            Change::Delay { delay } => {
+                (MultisigChangeType::UpdateChangeDelay, ChangeFields { new_delay: Some(delay), ..fields })
+            }
+            Change::Weight { address, weight } => (
+                MultisigChangeType::UpdateSignerWeight,
+                ChangeFields { old_address: Some(address), new_weight: Some(weight), ..fields },
+            ),
+            Change::GrantRole { address, role } => (
+                MultisigChangeType::GrantRole,
+                ChangeFields { new_address: Some(address), role: Some(role.into()), ..fields },
+            ),
+            Change::RevokeRole { address, role } => (
+                MultisigChangeType::RevokeRole,
+                ChangeFields { old_address: Some(address), role: Some(role.into()), ..fields },
+            ),
+        }
+    }
+}
+
+fn parse_keypair(suri: &str) -> Result<Keypair, String> {
+    let uri = SecretUri::from_str(suri).map_err(|error| format!("invalid secret URI: {error}"))?;
+    Keypair::from_uri(&uri).map_err(|error| format!("invalid secret URI: {error}"))
+}
+
+#[derive(Serialize)]
+struct Created {
+    hackathon_id: u32,
+}
+
+#[derive(Serialize)]
+struct Deposited {
+    hackathon_id: u32,
+    amount: String,
+}
+
+#[derive(Serialize)]
+struct Signed {
+    signed: Vec<u32>,
+}
+
+#[derive(Serialize)]
+struct Proposed {
+    change_id: u32,
+}
+
+#[derive(Serialize)]
+struct ChangeOutcome {
+    change_id: u32,
+    status: &'static str,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    let contract = cli
+        .contract
+        .ok_or("no contract address; pass --contract or set HACKATHONIA_CONTRACT")?;
+    let suri = cli.suri.ok_or("no signing account; pass --suri or set HACKATHONIA_SURI")?;
+    let signer = parse_keypair(&suri)?;
+    let mut client = EscrowClient::connect(&cli.url, contract, signer).await?;
+    let format = cli.output;
+
+    match cli.command {
+        Command::Create { file } => {
+            let spec = HackathonSpec::load(&file)?;
+            let signers = spec.signer_addresses()?;
+            let funds = spec.funds_value()?;
+            let hackathon_id = client
+                .create_hackathon(spec.name, spec.description, signers, spec.required_signatures, funds)
+                .await?;
+            output::print(format, &Created { hackathon_id }, |created| {
+                Table::fields(vec![("hackathon_id", created.hackathon_id.to_string())])
+            });
+        }
+        Command::Deposit { hackathon_id, amount } => {
+            client.deposit_funds(hackathon_id, amount).await?;
+            let deposited = Deposited { hackathon_id, amount: amount.to_string() };
+            output::print(format, &deposited, |deposited| {
+                Table::fields(vec![
+                    ("hackathon_id", deposited.hackathon_id.to_string()),
+                    ("amount", deposited.amount.clone()),
+                ])
+            });
+        }
+        Command::Pending { hackathon } => {
+            let requests = match hackathon {
+                Some(hackathon_id) => pending_for_hackathon(&mut client, hackathon_id).await?,
+                None => pending_for_signer(&mut client).await?,
+            };
+            let rows: Vec<PendingRow> = requests.iter().map(PendingRow::from).collect();
+            output::print(format, &rows, |rows| {
+                let mut table = Table::new(vec![
+                    "REQUEST",
+                    "HACKATHON",
+                    "RECIPIENT",
+                    "AMOUNT",
+                    "SIGNATURES",
+                    "WEIGHT",
+                    "CAN SIGN",
+                    "SIGNED",
+                    "REASON",
+                ]);
+                for row in rows {
+                    table.row(vec![
+                        row.request_id.to_string(),
+                        format!("{} ({})", row.hackathon_name, row.hackathon_id),
+                        row.recipient.clone(),
+                        row.amount.clone(),
+                        format!("{}/{}", row.signatures_provided, row.signatures_needed),
+                        format!("{}/{}", row.weight_provided, row.weight_required),
+                        yes_no(row.can_sign),
+                        yes_no(row.has_signed),
+                        row.reason.clone(),
+                    ]);
+                }
+                table
+            });
+        }
+        Command::Sign { request_ids } => {
+            let signed = if let [request_id] = request_ids[..] {
+                client.sign_payout(request_id).await?;
+                vec![request_id]
+            } else {
+                client.batch_sign_payouts(request_ids).await?
+            };
+            output::print(format, &Signed { signed }, |signed| {
+                let mut table = Table::new(vec!["SIGNED"]);
+                for request_id in &signed.signed {
+                    table.row(vec![request_id.to_string()]);
+                }
+                table
+            });
+        }
+        Command::Propose { hackathon_id, change } => {
+            let (change_type, fields) = change.into_request();
+            let change_id = client
+                .request_multisig_change(
+                    hackathon_id,
+                    change_type,
+                    fields.old_address,
+                    fields.new_address,
+                    fields.new_threshold,
+                    fields.new_organizer,
+                    fields.new_delay,
+                    fields.new_weight,
+                    fields.role,
+                )
+                .await?;
+            output::print(format, &Proposed { change_id }, |proposed| {
+                Table::fields(vec![("change_id", proposed.change_id.to_string())])
+            });
+        }
+        Command::Execute { change_id } => {
+            client.execute_multisig_change(change_id).await?;
+            print_change_outcome(format, change_id, "executed");
+        }
+        Command::Cancel { change_id } => {
+            client.cancel_multisig_change(change_id).await?;
+            let change = client.get_multisig_change_request(change_id).await?;
+            print_change_outcome(format, change_id, if change.is_cancelled { "cancelled" } else { "vetoed" });
+        }
+        Command::Stats { hackathon_id } => {
+            let stats = client.get_hackathon_stats(hackathon_id).await?;
+            output::print(format, &StatsView::new(hackathon_id, &stats), |stats| {
+                Table::fields(vec![
+                    ("hackathon_id", stats.hackathon_id.to_string()),
+                    ("total_funds_deposited", stats.total_funds_deposited.clone()),
+                    ("total_funds_paid_out", stats.total_funds_paid_out.clone()),
+                    ("remaining_balance", stats.remaining_balance.clone()),
+                    ("executed_requests", stats.executed_requests.to_string()),
+                    ("pending_requests", stats.pending_requests.to_string()),
+                    ("multisig_count", stats.multisig_count.to_string()),
+                    ("required_signatures", stats.required_signatures.to_string()),
+                    ("days_since_creation", stats.days_since_creation.to_string()),
+                ])
+            });
+        }
+        Command::Report { hackathon_id, from, to, format } => {
+            let summary = client
+                .get_accounting_summary(hackathon_id, from, to.unwrap_or(u64::MAX))
+                .await?;
+            let entries = ledger_entries(&mut client, &summary).await?;
+            let balance = client.get_hackathon_balance(hackathon_id).await?;
+            let statement = Statement::new(&summary, &entries, balance);
+            if !statement.reconciled {
+                eprintln!("warning: statement does not reconcile with the hackathon balance");
+            }
+            print!("{}", statement.render(format));
+        }
+    }
+    Ok(())
+}
+
+fn print_change_outcome(format: Format, change_id: u32, status: &'static str) {
+    output::print(format, &ChangeOutcome { change_id, status }, |outcome| {
+        Table::fields(vec![("change_id", outcome.change_id.to_string()), ("status", outcome.status.to_string())])
+    });
+}
+
+fn yes_no(value: bool) -> String {
+    String::from(if value { "yes" } else { "no" })
+}
+
+/// Every pending request the signing account can act on, across all pages: it approves
+/// payouts of the hackathon and has not signed the request yet
+async fn pending_for_signer(client: &mut EscrowClient) -> hackathonia_client::Result<Vec<PayoutRequestWithContext>> {
+    let signer = client.signer_address();
+    let mut requests = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = client.get_pending_requests_for_address(signer, cursor, MAX_PAGE_SIZE).await?;
+        requests.extend(page.items.into_iter().filter(|request| request.can_sign && !request.has_signed));
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => return Ok(requests),
+        }
+    }
+}
+
+/// Every pending request of one hackathon, with context for the signing account
+async fn pending_for_hackathon(
+    client: &mut EscrowClient,
+    hackathon_id: u32,
+) -> hackathonia_client::Result<Vec<PayoutRequestWithContext>> {
+    let signer = client.signer_address();
+    let mut requests = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = client.get_pending_requests(hackathon_id, cursor, MAX_PAGE_SIZE).await?;
+        for request_id in page.items {
+            requests.push(client.get_payout_request_with_context(request_id, signer).await?);
+        }
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => return Ok(requests),
+        }
+    }
+}
+
+/// Ledger entries within the period of `summary`
+async fn ledger_entries(
+    client: &mut EscrowClient,
+    summary: &AccountingSummary,
+) -> hackathonia_client::Result<Vec<LedgerEntry>> {
+    let wanted = summary.entry_count as usize;
+    let mut entries = Vec::new();
+    let mut cursor = summary.first_sequence;
+    while entries.len() < wanted {
+        let page = client.get_ledger_entries(summary.hackathon_id, cursor, MAX_PAGE_SIZE).await?;
+        entries.extend(page.items);
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+    entries.truncate(wanted);
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn parses_commands_and_addresses() {
+        Cli::command().debug_assert();
+
+        let address = format!("0x{}", "11".repeat(20));
+        let cli = Cli::try_parse_from([
+            "hackathonia",
+            "--contract",
+            &address,
+            "--output",
+            "json",
+            "propose",
+            "3",
+            "replace-signer",
+            &address,
+            &"22".repeat(20),
+        ])
+        .unwrap();
+        assert_eq!(cli.contract, Some(Address::from([0x11; 20])));
+        assert_eq!(cli.output, Format::Json);
+        assert!(matches!(
+            cli.command,
+            Command::Propose { hackathon_id: 3, change: Change::ReplaceSigner { new, .. } } if new == Address::from([0x22; 20])
+        ));
+
+        assert!(Cli::try_parse_from(["hackathonia", "sign"]).is_err());
+        let cli = Cli::try_parse_from(["hackathonia", "report", "2", "--from", "100", "--format", "json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Report { hackathon_id: 2, from: 100, to: None, format: StatementFormat::Json }
+        ));
+        let cli = Cli::try_parse_from(["hackathonia", "propose", "1", "grant-role", &address, "treasurer"]).unwrap();
+        let Command::Propose { change, .. } = cli.command else { panic!("expected propose") };
+        let (change_type, fields) = change.into_request();
+        assert_eq!(change_type, MultisigChangeType::GrantRole);
+        assert_eq!((fields.new_address, fields.role), (Some(Address::from([0x11; 20])), Some(UserRole::Treasurer)));
+        assert!(cli.suri.is_none());
+        let cli = Cli::try_parse_from(["hackathonia", "--suri", "//Bob", "cancel", "7"]).unwrap();
+        assert!(matches!(cli.command, Command::Cancel { change_id: 7 }));
+
+        assert!(parse_address("0x1234").is_err());
+        assert!(parse_keypair("//Alice").is_ok());
+    }
+}
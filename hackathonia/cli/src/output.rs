@@ -0,0 +1,150 @@
+use clap::ValueEnum;
+use hackathonia_client::hackathon_escrow::{HackathonStats, PayoutRequestWithContext};
+use hackathonia_client::hex_address;
+use serde::Serialize;
+
+/// How command results are printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for people
+    Table,
+    /// One JSON document for scripts
+    Json,
+}
+
+/// Pending payout request as printed by `hackathonia pending`
+#[derive(Debug, Serialize)]
+pub struct PendingRow {
+    pub request_id: u32,
+    pub hackathon_id: u32,
+    pub hackathon_name: String,
+    pub recipient: String,
+    /// Decimal string, as amounts can exceed JSON's safe integer range
+    pub amount: String,
+    pub reason: String,
+    pub signatures_provided: u32,
+    pub signatures_needed: u32,
+    pub weight_provided: u32,
+    pub weight_required: u32,
+    pub can_sign: bool,
+    pub has_signed: bool,
+}
+
+impl From<&PayoutRequestWithContext> for PendingRow {
+    fn from(context: &PayoutRequestWithContext) -> Self {
+        Self {
+            request_id: context.request.request_id,
+            hackathon_id: context.request.hackathon_id,
+            hackathon_name: context.hackathon_name.clone(),
+            recipient: hex_address(&context.request.recipient),
+            amount: context.request.amount.to_string(),
+            reason: context.request.reason.clone(),
+            signatures_provided: context.signatures_provided,
+            signatures_needed: context.signatures_needed,
+            weight_provided: context.weight_provided,
+            weight_required: context.weight_required,
+            can_sign: context.can_sign,
+            has_signed: context.has_signed,
+        }
+    }
+}
+
+/// Hackathon statistics as printed by `hackathonia stats`
+#[derive(Debug, Serialize)]
+pub struct StatsView {
+    pub hackathon_id: u32,
+    pub total_funds_deposited: String,
+    pub total_funds_paid_out: String,
+    pub remaining_balance: String,
+    pub executed_requests: u32,
+    pub pending_requests: u32,
+    pub multisig_count: u32,
+    pub required_signatures: u32,
+    pub days_since_creation: u64,
+}
+
+impl StatsView {
+    pub fn new(hackathon_id: u32, stats: &HackathonStats) -> Self {
+        Self {
+            hackathon_id,
+            total_funds_deposited: stats.total_funds_deposited.to_string(),
+            total_funds_paid_out: stats.total_funds_paid_out.to_string(),
+            remaining_balance: stats.remaining_balance.to_string(),
+            executed_requests: stats.executed_requests,
+            pending_requests: stats.pending_requests,
+            multisig_count: stats.multisig_count,
+            required_signatures: stats.required_signatures,
+            days_since_creation: stats.days_since_creation,
+        }
+    }
+}
+
+/// Print `value` as JSON, or as a table built by `table`
+pub fn print<T: Serialize>(format: Format, value: &T, table: impl FnOnce(&T) -> Table) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(value).expect("output is serializable")),
+        Format::Table => print!("{}", table(value).render()),
+    }
+}
+
+/// Plain-text table with left-aligned columns
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Self { headers, rows: Vec::new() }
+    }
+
+    /// Two-column table of field names and values
+    pub fn fields(fields: Vec<(&'static str, String)>) -> Self {
+        let mut table = Self::new(vec!["FIELD", "VALUE"]);
+        for (name, value) in fields {
+            table.row(vec![name.to_string(), value]);
+        }
+        table
+    }
+
+    pub fn row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| header.len()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+
+        let mut out = line(self.headers.clone());
+        for row in &self.rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_pads_columns_to_the_widest_cell() {
+        let mut table = Table::new(vec!["ID", "NAME"]);
+        table.row(vec![String::from("1"), String::from("Stadium Hack")]);
+        table.row(vec![String::from("120"), String::from("x")]);
+        assert_eq!(table.render(), "ID   NAME\n1    Stadium Hack\n120  x\n");
+    }
+}
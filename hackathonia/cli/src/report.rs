@@ -0,0 +1,187 @@
+use clap::ValueEnum;
+use hackathonia_client::hackathon_escrow::{AccountingSummary, LedgerEntry};
+use hackathonia_client::hex_address;
+use ink::primitives::U256;
+use serde::Serialize;
+
+/// File format of an accounting statement
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatementFormat {
+    /// One row per ledger entry, between opening and closing balance rows
+    Csv,
+    /// Summary and entries in one JSON document
+    Json,
+}
+
+/// Ledger entry as exported in a statement
+#[derive(Debug, Serialize)]
+pub struct StatementEntry {
+    pub sequence: u32,
+    pub timestamp: u64,
+    pub kind: String,
+    pub reference: u32,
+    pub counterparty: String,
+    pub amount: String,
+    pub fee: String,
+    pub credited: String,
+    pub debited: String,
+    pub balance_after: String,
+}
+
+impl From<&LedgerEntry> for StatementEntry {
+    fn from(entry: &LedgerEntry) -> Self {
+        Self {
+            sequence: entry.sequence,
+            timestamp: entry.timestamp,
+            kind: format!("{:?}", entry.kind),
+            reference: entry.reference,
+            counterparty: hex_address(&entry.counterparty),
+            amount: entry.amount.to_string(),
+            fee: entry.fee.to_string(),
+            credited: entry.credited().to_string(),
+            debited: entry.debited().to_string(),
+            balance_after: entry.balance_after.to_string(),
+        }
+    }
+}
+
+/// Accounting statement of one hackathon over a period, as printed by `hackathonia report`
+#[derive(Debug, Serialize)]
+pub struct Statement {
+    pub hackathon_id: u32,
+    pub period_start: u64,
+    /// `None` for a statement running up to now
+    pub period_end: Option<u64>,
+    pub opening_balance: String,
+    pub inflows: String,
+    pub outflows: String,
+    pub fees: String,
+    pub closing_balance: String,
+    /// Balance reported by `get_hackathon_balance` when the statement was made
+    pub contract_balance: String,
+    /// Whether the entries add up to the closing balance and the ledger to the contract balance
+    pub reconciled: bool,
+    pub entries: Vec<StatementEntry>,
+}
+
+impl Statement {
+    pub fn new(summary: &AccountingSummary, entries: &[LedgerEntry], contract_balance: U256) -> Self {
+        let mut balance = summary.opening_balance;
+        let mut entries_add_up = entries.len() == summary.entry_count as usize;
+        for entry in entries {
+            balance = balance.saturating_add(entry.credited()).saturating_sub(entry.debited());
+            entries_add_up &= balance == entry.balance_after;
+        }
+        entries_add_up &= balance == summary.closing_balance;
+
+        Self {
+            hackathon_id: summary.hackathon_id,
+            period_start: summary.period_start,
+            period_end: (summary.period_end != u64::MAX).then_some(summary.period_end),
+            opening_balance: summary.opening_balance.to_string(),
+            inflows: summary.inflows.to_string(),
+            outflows: summary.outflows.to_string(),
+            fees: summary.fees.to_string(),
+            closing_balance: summary.closing_balance.to_string(),
+            contract_balance: contract_balance.to_string(),
+            reconciled: entries_add_up && summary.is_reconciled && summary.current_balance == contract_balance,
+            entries: entries.iter().map(StatementEntry::from).collect(),
+        }
+    }
+
+    pub fn render(&self, format: StatementFormat) -> String {
+        match format {
+            StatementFormat::Json => {
+                format!("{}\n", serde_json::to_string_pretty(self).expect("statement is serializable"))
+            }
+            StatementFormat::Csv => self.to_csv(),
+        }
+    }
+
+    /// Every field is a number, an address or a kind name, so nothing needs quoting
+    fn to_csv(&self) -> String {
+        let mut out =
+            String::from("sequence,timestamp,kind,reference,counterparty,amount,fee,credited,debited,balance\n");
+        out.push_str(&format!(",{},OpeningBalance,,,,,,,{}\n", self.period_start, self.opening_balance));
+        for entry in &self.entries {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                entry.sequence,
+                entry.timestamp,
+                entry.kind,
+                entry.reference,
+                entry.counterparty,
+                entry.amount,
+                entry.fee,
+                entry.credited,
+                entry.debited,
+                entry.balance_after,
+            ));
+        }
+        let period_end = self.period_end.map(|end| end.to_string()).unwrap_or_default();
+        out.push_str(&format!(",{period_end},ClosingBalance,,,,,,,{}\n", self.closing_balance));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hackathonia_client::hackathon_escrow::LedgerEntryKind;
+    use ink::primitives::Address;
+
+    fn entry(sequence: u32, kind: LedgerEntryKind, amount: u32, fee: u32, balance_after: u32) -> LedgerEntry {
+        LedgerEntry {
+            sequence,
+            kind,
+            reference: 1,
+            counterparty: Address::from([0xab; 20]),
+            amount: U256::from(amount),
+            fee: U256::from(fee),
+            balance_after: U256::from(balance_after),
+            timestamp: u64::from(sequence) * 100,
+        }
+    }
+
+    #[test]
+    fn statement_reconciles_and_renders() {
+        let summary = AccountingSummary {
+            hackathon_id: 1,
+            period_start: 100,
+            period_end: u64::MAX,
+            opening_balance: U256::from(1000u32),
+            inflows: U256::from(450u32),
+            outflows: U256::from(200u32),
+            fees: U256::from(50u32),
+            closing_balance: U256::from(1250u32),
+            first_sequence: 1,
+            entry_count: 2,
+            current_balance: U256::from(1250u32),
+            is_reconciled: true,
+        };
+        let entries =
+            [entry(1, LedgerEntryKind::Deposit, 500, 50, 1450), entry(2, LedgerEntryKind::Payout, 200, 0, 1250)];
+
+        let statement = Statement::new(&summary, &entries, U256::from(1250u32));
+        assert!(statement.reconciled);
+        assert_eq!(statement.period_end, None);
+        let address = format!("0x{}", "ab".repeat(20));
+        assert_eq!(
+            statement.render(StatementFormat::Csv),
+            format!(
+                "sequence,timestamp,kind,reference,counterparty,amount,fee,credited,debited,balance\n\
+                 ,100,OpeningBalance,,,,,,,1000\n\
+                 1,100,Deposit,1,{address},500,50,450,0,1450\n\
+                 2,200,Payout,1,{address},200,0,0,200,1250\n\
+                 ,,ClosingBalance,,,,,,,1250\n"
+            )
+        );
+        let json: serde_json::Value = serde_json::from_str(&statement.render(StatementFormat::Json)).unwrap();
+        assert_eq!(json["entries"][0]["credited"], "450");
+        assert_eq!(json["closing_balance"], "1250");
+
+        // A missing entry or a drifted contract balance is flagged
+        assert!(!Statement::new(&summary, &entries[..1], U256::from(1250u32)).reconciled);
+        assert!(!Statement::new(&summary, &entries, U256::from(1300u32)).reconciled);
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use hackathonia_client::{parse_address, Balance};
+use ink::primitives::Address;
+use serde::Deserialize;
+
+/// Hackathon described in a TOML file for `hackathonia create`
+///
+/// ```toml
+/// name = "Stadium Hack"
+/// description = "Build on Polkadot"
+/// signers = ["0x8eaf04151687736326c9fea17e25fc5287613693", "0x90b5ab205c6974c9ea841be688864633dc9ca8a3"]
+/// required_signatures = 2
+/// funds = "1000000000000000000000000"
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HackathonSpec {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub signers: Vec<String>,
+    pub required_signatures: u32,
+    /// Native value locked into the escrow on creation, as a decimal string since TOML
+    /// integers stop at `i64::MAX`
+    #[serde(default)]
+    pub funds: Option<String>,
+}
+
+impl HackathonSpec {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|error| format!("cannot read {}: {error}", path.display()))?;
+        toml::from_str(&text).map_err(|error| format!("invalid hackathon file {}: {error}", path.display()))
+    }
+
+    pub fn signer_addresses(&self) -> Result<Vec<Address>, String> {
+        self.signers.iter().map(|signer| parse_address(signer)).collect()
+    }
+
+    pub fn funds_value(&self) -> Result<Balance, String> {
+        match &self.funds {
+            Some(funds) => funds.parse().map_err(|error| format!("invalid funds {funds}: {error}")),
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_hackathon_file() {
+        let spec: HackathonSpec = toml::from_str(
+            r#"
+            name = "Stadium Hack"
+            signers = ["0x8eaf04151687736326c9fea17e25fc5287613693"]
+            required_signatures = 1
+            funds = "100000000000000000000000000"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(spec.name, "Stadium Hack");
+        assert_eq!(spec.description, "");
+        assert_eq!(spec.funds_value(), Ok(100_000_000_000_000_000_000_000_000));
+        assert_eq!(spec.signer_addresses().unwrap(), vec![Address::from([
+            0x8e, 0xaf, 0x04, 0x15, 0x16, 0x87, 0x73, 0x63, 0x26, 0xc9, 0xfe, 0xa1, 0x7e, 0x25, 0xfc, 0x52, 0x87,
+            0x61, 0x36, 0x93
+        ])]);
+
+        let bad = HackathonSpec { signers: vec![String::from("0x1234")], funds: Some(String::from("-1")), ..spec };
+        assert!(bad.signer_addresses().is_err());
+        assert!(bad.funds_value().is_err());
+        let unfunded = HackathonSpec { funds: None, ..bad };
+        assert_eq!(unfunded.funds_value(), Ok(0));
+        assert!(toml::from_str::<HackathonSpec>("name = \"x\"\nsigners = []\nrequired_signatures = 1\nprize = 1").is_err());
+    }
+}
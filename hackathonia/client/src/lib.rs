@@ -0,0 +1,514 @@
+//! Typed client for the hackathon escrow contract.
+//!
+//! Calls are built with the contract's own generated call builders, so selectors and
+//! argument encoding always match the deployed ABI. Every message is dry-run first:
+//! a `ContractError` comes back as [`ClientError::Contract`] without submitting anything.
+//!
+//! ```no_run
+//! use hackathonia_client::{EscrowClient, ink_e2e};
+//!
+//! # async fn run() -> Result<(), hackathonia_client::ClientError> {
+//! let mut client = EscrowClient::deploy(
+//!     "ws://127.0.0.1:9944",
+//!     "target/ink/hackathonia.polkavm",
+//!     ink_e2e::alice(),
+//! )
+//! .await?;
+//! let signers = vec![ink_e2e::address_from_keypair::<ink::primitives::AccountId>(&ink_e2e::bob())];
+//! let hackathon_id = client
+//!     .create_hackathon("Hack".into(), "Build things".into(), signers, 1, 1_000_000)
+//!     .await?;
+//! let balance = client.get_hackathon_balance(hackathon_id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+
+use hackathonia::hackathon_escrow::*;
+use hackathonia::Escrow;
+use ink::codegen::ContractCallBuilder;
+use ink::env::call::utils::{DecodeMessageResult, EncodeArgsWith};
+use ink::env::{DefaultAbi, DefaultEnvironment};
+use ink::primitives::{Address, H256, U256};
+use ink_e2e::{CallBuilderFinal, ContractsBackend, Keypair, PolkadotConfig, RpcClient};
+
+pub use hackathonia::hackathon_escrow;
+pub use ink_e2e;
+
+/// Native balance attached to payable messages
+pub type Balance = <DefaultEnvironment as ink::env::Environment>::Balance;
+
+pub type Result<T> = core::result::Result<T, ClientError>;
+
+/// Contract call builder bound to one deployed escrow contract
+type ContractBuilder = <HackathonEscrowContract as ContractCallBuilder>::Type<DefaultAbi>;
+
+/// Failure of a client call
+#[derive(Debug)]
+pub enum ClientError {
+    /// The contract rejected the call
+    Contract(ContractError),
+    /// The node could not be reached
+    Connection(String),
+    /// The node rejected or failed to run the call
+    Node(Box<ink_e2e::Error>),
+    /// The call could not be dispatched to a message
+    Lang(ink::LangError),
+}
+
+impl ClientError {
+    /// The contract error, if the contract itself rejected the call
+    pub fn contract_error(&self) -> Option<&ContractError> {
+        match self {
+            ClientError::Contract(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Contract(error) => write!(f, "contract error: {error:?}"),
+            ClientError::Connection(error) => write!(f, "connection error: {error}"),
+            ClientError::Node(error) => write!(f, "node error: {error}"),
+            ClientError::Lang(error) => write!(f, "dispatch error: {error:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ContractError> for ClientError {
+    fn from(error: ContractError) -> Self {
+        ClientError::Contract(error)
+    }
+}
+
+impl From<ink_e2e::Error> for ClientError {
+    fn from(error: ink_e2e::Error) -> Self {
+        ClientError::Node(Box::new(error))
+    }
+}
+
+impl From<ink::LangError> for ClientError {
+    fn from(error: ink::LangError) -> Self {
+        ClientError::Lang(error)
+    }
+}
+
+/// Parse a `0x`-prefixed or bare 20-byte hex address
+pub fn parse_address(value: &str) -> core::result::Result<Address, String> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    let bytes: [u8; 20] = hex::decode(digits)
+        .map_err(|error| format!("invalid address {value}: {error}"))?
+        .try_into()
+        .map_err(|_| format!("invalid address {value}: expected 20 bytes"))?;
+    Ok(Address::from(bytes))
+}
+
+/// Full `0x`-prefixed hex form of an address
+pub fn hex_address(address: &Address) -> String {
+    format!("0x{}", hex::encode(address.as_bytes()))
+}
+
+/// Client for one deployed escrow contract, signing as a single account
+pub struct EscrowClient {
+    client: ink_e2e::Client<PolkadotConfig, DefaultEnvironment>,
+    contract: ContractBuilder,
+    address: Address,
+    signer: Keypair,
+}
+
+/// Generate a typed wrapper per contract message.
+///
+/// `tx` and `payable` messages are dry-run and then submitted; `query` and `view`
+/// messages are only dry-run. `tx`, `payable` and `query` unwrap the contract's `Result`.
+macro_rules! messages {
+    ($(
+        $(#[$attr:meta])*
+        $kind:ident $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;
+    )*) => {
+        impl EscrowClient {
+            $( messages!(@message $(#[$attr])* $kind $name($($arg: $ty),*) -> $ret); )*
+        }
+    };
+    (@message $(#[$attr:meta])* tx $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, $($arg: $ty),*) -> Result<$ret> {
+            let message = self.contract.$name($($arg),*);
+            self.transact(&message, 0).await
+        }
+    };
+    (@message $(#[$attr:meta])* payable $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, $($arg: $ty,)* value: Balance) -> Result<$ret> {
+            let message = self.contract.$name($($arg),*);
+            self.transact(&message, value).await
+        }
+    };
+    (@message $(#[$attr:meta])* query $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, $($arg: $ty),*) -> Result<$ret> {
+            let message = self.contract.$name($($arg),*);
+            Ok(self.view(&message).await??)
+        }
+    };
+    (@message $(#[$attr:meta])* view $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, $($arg: $ty),*) -> Result<$ret> {
+            let message = self.contract.$name($($arg),*);
+            self.view(&message).await
+        }
+    };
+}
+
+impl EscrowClient {
+    /// Connect to an already deployed contract
+    pub async fn connect(url: &str, address: Address, signer: Keypair) -> Result<Self> {
+        let client = Self::node(url, Vec::<PathBuf>::new()).await?;
+        Ok(Self {
+            client,
+            contract: ink_e2e::create_call_builder::<HackathonEscrowContract>(address),
+            address,
+            signer,
+        })
+    }
+
+    /// Upload and instantiate the contract from a built `.polkavm` artifact
+    pub async fn deploy(url: &str, artifact: impl Into<PathBuf>, signer: Keypair) -> Result<Self> {
+        let artifact = artifact.into();
+        let name = artifact
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut client = Self::node(url, [artifact]).await?;
+
+        let mut constructor = HackathonEscrowContractRef::new();
+        let contract = client.instantiate(&name, &signer, &mut constructor).submit().await?;
+        Ok(Self {
+            client,
+            contract: contract.call_builder::<HackathonEscrowContract>(),
+            address: contract.addr,
+            signer,
+        })
+    }
+
+    /// Open a connection to the node (internal function)
+    async fn node<P: Into<PathBuf>>(
+        url: &str,
+        artifacts: impl IntoIterator<Item = P>,
+    ) -> Result<ink_e2e::Client<PolkadotConfig, DefaultEnvironment>> {
+        let rpc = RpcClient::from_url(url)
+            .await
+            .map_err(|error| ClientError::Connection(error.to_string()))?;
+        ink_e2e::Client::new(rpc, artifacts, url.to_string())
+            .await
+            .map_err(|error| ClientError::Connection(error.to_string()))
+    }
+
+    /// Address of the contract this client talks to
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Address of the account signing calls
+    pub fn signer_address(&self) -> Address {
+        ink_e2e::address_from_keypair::<ink::primitives::AccountId>(&self.signer)
+    }
+
+    /// Sign subsequent calls with another account
+    pub fn set_signer(&mut self, signer: Keypair) {
+        self.signer = signer;
+    }
+
+    /// Dry-run a message, then submit it if the contract accepts it (internal function)
+    async fn transact<Args, T>(
+        &mut self,
+        message: &CallBuilderFinal<DefaultEnvironment, Args, core::result::Result<T, ContractError>, DefaultAbi>,
+        value: Balance,
+    ) -> Result<T>
+    where
+        Args: Sync + Send + EncodeArgsWith<DefaultAbi> + Clone,
+        core::result::Result<T, ContractError>: Send + DecodeMessageResult<DefaultAbi>,
+        CallBuilderFinal<DefaultEnvironment, Args, core::result::Result<T, ContractError>, DefaultAbi>: Clone,
+    {
+        let mut call = self.client.call(&self.signer, message);
+        call.value(value);
+        contract_result(call.dry_run().await?.message_result())?;
+        contract_result(call.submit().await?.dry_run.message_result())
+    }
+
+    /// Dry-run a message and decode its return value (internal function)
+    async fn view<Args, R>(&mut self, message: &CallBuilderFinal<DefaultEnvironment, Args, R, DefaultAbi>) -> Result<R>
+    where
+        Args: Sync + Send + EncodeArgsWith<DefaultAbi> + Clone,
+        R: Send + DecodeMessageResult<DefaultAbi>,
+        CallBuilderFinal<DefaultEnvironment, Args, R, DefaultAbi>: Clone,
+    {
+        Ok(self.client.call(&self.signer, message).dry_run().await?.message_result()?)
+    }
+}
+
+/// Unwrap a message result returning the contract's `Result` (internal function)
+fn contract_result<T>(result: ink::MessageResult<core::result::Result<T, ContractError>>) -> Result<T> {
+    Ok(result??)
+}
+
+messages! {
+    /// Pause or unpause the contract (only owner)
+    tx set_paused(paused: bool) -> ();
+    /// Freeze or unfreeze a single hackathon on suspected compromise (only owner)
+    tx set_hackathon_frozen(hackathon_id: u32, frozen: bool) -> ();
+    /// Nominate a new owner, who must accept (only owner)
+    tx transfer_ownership(new_owner: Address) -> ();
+    /// Accept a pending ownership transfer (only the nominated owner)
+    tx accept_ownership() -> ();
+    /// Replace the contract code, keeping storage (only owner)
+    tx set_code_hash(code_hash: H256) -> ();
+    /// Convert up to `limit` stored entries from an older layout (only owner)
+    tx migrate_storage(limit: u32) -> MigrationStatus;
+    /// Get the progress of the storage migration
+    view get_migration_status() -> MigrationStatus;
+    /// Set the platform fee in basis points and when it is charged (only owner)
+    tx set_platform_fee(fee_bps: u16, mode: FeeMode) -> ();
+    /// Exempt a hackathon from the platform fee on later deposits and payouts, not its creation funds (only owner)
+    tx set_fee_exemption(hackathon_id: u32, exempt: bool) -> ();
+    /// Withdraw collected platform fees (only owner)
+    tx withdraw_fees(recipient: Address, amount: U256) -> ();
+    /// Get the platform fee settings and treasury totals
+    view get_fee_report() -> FeeReport;
+    /// Get the platform fees collected from a hackathon
+    view get_hackathon_fees(hackathon_id: u32) -> U256;
+    /// Compare the contract balance with the sum of escrows and reserved funds
+    view check_solvency() -> SolvencyReport;
+    /// Move surplus funds not owed to any escrow, fee treasury or bond (only owner)
+    tx sweep_surplus(amount: U256, target: SweepTarget) -> u32;
+    /// Get a recorded surplus sweep
+    query get_surplus_sweep(sweep_id: u32) -> SurplusSweep;
+    /// Get contract-wide configuration
+    view get_contract_config() -> ContractConfig;
+
+    /// Create a hackathon escrow funded with `value`
+    payable create_hackathon(
+        name: String,
+        description: String,
+        multisig_addresses: Vec<Address>,
+        required_signatures: u32
+    ) -> u32;
+    /// Deposit `value` into a hackathon escrow (anyone until a treasurer is granted, then only treasurers)
+    payable deposit_funds(hackathon_id: u32) -> ();
+    /// Add a multisig address to an existing hackathon with no change delay (only organizer)
+    tx add_multisig_address(hackathon_id: u32, multisig_address: Address) -> ();
+    /// Request a multi-sig change (organizer or multisig can request)
+    #[allow(clippy::too_many_arguments)]
+    tx request_multisig_change(
+        hackathon_id: u32,
+        change_type: MultisigChangeType,
+        old_address: Option<Address>,
+        new_address: Option<Address>,
+        new_threshold: Option<u32>,
+        new_organizer: Option<Address>,
+        new_delay: Option<u64>,
+        new_weight: Option<u32>,
+        role: Option<UserRole>
+    ) -> u32;
+    /// Sign a multi-sig change request
+    tx sign_multisig_change(change_id: u32) -> ();
+    /// Execute an approved time-locked change once its delay has passed (anyone can call)
+    tx execute_multisig_change(change_id: u32) -> ();
+    /// Veto an approved time-locked change (only multisig, except the signer it displaces); the
+    /// change is cancelled once the vetoing weight blocks the change majority
+    tx cancel_multisig_change(change_id: u32) -> ();
+    /// Delegate the caller's signing right for a hackathon until `expires_at` (only multisig)
+    tx delegate_signing(hackathon_id: u32, delegate: Address, expires_at: u64) -> ();
+    /// Revoke the caller's signing delegation for a hackathon
+    tx revoke_delegation(hackathon_id: u32) -> ();
+    /// Get a signer's delegation for a hackathon
+    query get_delegation(hackathon_id: u32, signer: Address) -> SigningDelegation;
+
+    /// Request a payout (only multisig addresses can request)
+    tx request_payout(hackathon_id: u32, recipient: Address, amount: U256, reason: String) -> u32;
+    /// Sign a payout request (only multisig addresses)
+    tx sign_payout(request_id: u32) -> ();
+    /// Submit a bundle of off-chain signed approvals for a payout (anyone can relay)
+    tx submit_payout_approvals(request_id: u32, approvals: Vec<PayoutApproval>) -> ();
+    /// Get the SCALE-encoded payload a signer must sign off-chain to approve a payout
+    query get_payout_approval_payload(request_id: u32, signer: Address) -> Vec<u8>;
+    /// Get a signer's next off-chain approval nonce
+    view get_approval_nonce(signer: Address) -> u64;
+    /// Release an approved payout once its dispute window has passed (anyone can call)
+    tx release_payout(request_id: u32) -> ();
+    /// Set the dispute window and bond for future payout approvals (only organizer)
+    tx set_dispute_config(hackathon_id: u32, dispute_period: u64, dispute_bond: U256) -> ();
+    /// Set what happens when a recipient contract fails or rejects a payout notification (only organizer)
+    tx set_payout_callback_policy(hackathon_id: u32, policy: PayoutCallbackPolicy) -> ();
+    /// Get the payout notification failure policy of a hackathon
+    query get_payout_callback_policy(hackathon_id: u32) -> PayoutCallbackPolicy;
+    /// Set amount-based approval tiers and a per-period spending cap (only organizer)
+    tx set_approval_policy(
+        hackathon_id: u32,
+        approval_tiers: Vec<ApprovalTier>,
+        spending_period: u64,
+        spending_cap: U256
+    ) -> ();
+    /// Register a participant who may dispute payouts (only organizer)
+    tx register_participant(hackathon_id: u32, participant: Address) -> ();
+    /// Check if an address is a registered participant
+    view is_participant(hackathon_id: u32, address: Address) -> bool;
+    /// Dispute an approved payout during its dispute window, posting `value` as the bond
+    payable dispute_payout(request_id: u32, reason: String) -> ();
+    /// Vote on the resolution of a payout dispute (only multisig addresses)
+    tx resolve_dispute(request_id: u32, resolution: DisputeResolution) -> ();
+    /// Cancel the payout of a dispute left unresolved past its deadline, returning the bond (anyone can call)
+    tx settle_expired_dispute(request_id: u32) -> ();
+    /// Get dispute details for a payout request
+    query get_payout_dispute(request_id: u32) -> PayoutDispute;
+    /// Deactivate hackathon (only organizer)
+    tx deactivate_hackathon(hackathon_id: u32) -> ();
+    /// Cancel a payout request that has not been approved (only treasurers, or the organizer while none is granted)
+    tx cancel_payout_request(request_id: u32) -> ();
+    /// Return unspent funds of a deactivated hackathon to a funder (only treasurers, or the organizer while none is granted)
+    tx refund_deposit(hackathon_id: u32, funder: Address, amount: U256) -> ();
+    /// Get the funds an address put into a hackathon that can still be refunded to it
+    view get_funder_deposit(hackathon_id: u32, funder: Address) -> U256;
+    /// Batch sign multiple payout requests
+    tx batch_sign_payouts(request_ids: Vec<u32>) -> Vec<u32>;
+    /// Update hackathon metadata (only organizer)
+    tx update_hackathon_metadata(hackathon_id: u32, name: Option<String>, description: Option<String>) -> ();
+
+    /// Get hackathon details
+    query get_hackathon(hackathon_id: u32) -> HackathonEscrow;
+    /// Get payout request details
+    query get_payout_request(request_id: u32) -> PayoutRequest;
+    /// Get contract balance
+    view get_contract_balance() -> U256;
+    /// Get hackathon balance
+    query get_hackathon_balance(hackathon_id: u32) -> U256;
+    /// Get all multisig addresses for a hackathon
+    query get_multisig_addresses(hackathon_id: u32) -> Vec<Address>;
+    /// Get pending payout requests for a hackathon, starting at request ID `cursor`
+    query get_pending_requests(hackathon_id: u32, cursor: u32, limit: u32) -> Page<u32>;
+    /// Get multi-sig change request details
+    query get_multisig_change_request(change_id: u32) -> MultisigChangeRequest;
+    /// Get pending multi-sig change requests for a hackathon, starting at change ID `cursor`
+    query get_pending_multisig_changes(hackathon_id: u32, cursor: u32, limit: u32) -> Page<u32>;
+    /// Get required signer weight for multi-sig changes (2/3 majority)
+    query get_required_change_signatures(hackathon_id: u32) -> u32;
+    /// Get every signer of a hackathon with its weight
+    query get_signer_weights(hackathon_id: u32) -> Vec<(Address, u32)>;
+    /// Check if address can request changes (organizer or multisig)
+    query can_request_changes(hackathon_id: u32, address: Address) -> bool;
+    /// Get hackathon summary for dashboard
+    query get_hackathon_summary(hackathon_id: u32) -> HackathonSummary;
+    /// List hackathons matching `filter` in `sort` order; `cursor` is the hackathon ID to resume at
+    query list_hackathons(filter: HackathonFilter, sort: HackathonSort, cursor: u32, limit: u32) -> Page<HackathonListing>;
+    /// Get hackathons for an address (organizer or multisig), starting at hackathon ID `cursor`
+    query get_hackathons_for_address(address: Address, cursor: u32, limit: u32) -> Page<u32>;
+    /// Get hackathon statistics
+    query get_hackathon_stats(hackathon_id: u32) -> HackathonStats;
+    /// Get payout request with context for UI
+    query get_payout_request_with_context(request_id: u32, caller: Address) -> PayoutRequestWithContext;
+    /// Get pending requests with context for an address, starting at request ID `cursor`
+    query get_pending_requests_for_address(address: Address, cursor: u32, limit: u32) -> Page<PayoutRequestWithContext>;
+    /// Search hackathons by name (partial match), starting at hackathon ID `cursor`
+    query search_hackathons(search_term: String, cursor: u32, limit: u32) -> Page<u32>;
+    /// Get a hackathon's activity log, newest first
+    query get_recent_activity(hackathon_id: u32, cursor: u32, limit: u32) -> Page<ActivityItem>;
+    /// Get the number of activity log entries of a hackathon
+    view get_activity_count(hackathon_id: u32) -> u32;
+    /// Get a hackathon's ledger of funds movements, oldest first
+    query get_ledger_entries(hackathon_id: u32, cursor: u32, limit: u32) -> Page<LedgerEntry>;
+    /// Summarize a hackathon's ledger over a period and reconcile it against the escrow balance
+    query get_accounting_summary(hackathon_id: u32, period_start: u64, period_end: u64) -> AccountingSummary;
+    /// Get all roles an address holds in a hackathon (`Viewer` if none)
+    query get_user_role(hackathon_id: u32, address: Address) -> Vec<UserRole>;
+    /// Get a hackathon's payout requests with their disputes
+    query get_audit_payout_requests(hackathon_id: u32, cursor: u32, limit: u32) -> Page<AuditedPayout>;
+    /// Get a hackathon's multi-sig change requests
+    query get_audit_change_requests(hackathon_id: u32, cursor: u32, limit: u32) -> Page<MultisigChangeRequest>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::scale::Encode;
+
+    fn call_builder() -> ContractBuilder {
+        ink_e2e::create_call_builder::<HackathonEscrowContract>(Address::from([7; 20]))
+    }
+
+    /// Selector followed by the SCALE-encoded arguments, as sent to the contract
+    fn call_data<Args, R>(message: CallBuilderFinal<DefaultEnvironment, Args, R, DefaultAbi>) -> Vec<u8>
+    where
+        Args: EncodeArgsWith<DefaultAbi>,
+    {
+        message.params().exec_input().encode()
+    }
+
+    #[test]
+    fn messages_encode_selector_and_arguments() {
+        let recipient = Address::from([9; 20]);
+        let message = call_builder().request_payout(3, recipient, U256::from(500u32), String::from("Prize"));
+        let mut expected = vec![0xF6, 0x58, 0x96, 0x91];
+        Encode::encode_to(&(3u32, recipient, U256::from(500u32), String::from("Prize")), &mut expected);
+        assert_eq!(call_data(message), expected);
+
+        let message = call_builder().deposit_funds(4);
+        assert_eq!(call_data(message), [&[0x3A, 0xFD, 0xF2, 0x6B][..], &Encode::encode(&4u32)].concat());
+
+        let message = call_builder().set_paused(true);
+        assert_eq!(call_data(message), [&ink::selector_bytes!("set_paused")[..], &[1]].concat());
+
+        let message = call_builder().get_audit_payout_requests(1, 2, 3);
+        let mut expected = ink::selector_bytes!("get_audit_payout_requests").to_vec();
+        Encode::encode_to(&(1u32, 2u32, 3u32), &mut expected);
+        assert_eq!(call_data(message), expected);
+    }
+
+    #[test]
+    fn addresses_round_trip_through_hex() {
+        let address = Address::from([0xab; 20]);
+        assert_eq!(hex_address(&address), format!("0x{}", "ab".repeat(20)));
+        assert_eq!(parse_address(&hex_address(&address)), Ok(address));
+        assert_eq!(parse_address(&"AB".repeat(20)), Ok(address));
+        assert!(parse_address("0x1234").is_err());
+        assert!(parse_address("0xzz").is_err());
+    }
+
+    #[test]
+    fn message_results_map_to_client_errors() {
+        assert_eq!(contract_result::<u32>(Ok(Ok(7))).unwrap(), 7);
+
+        // Contract errors come back encoded in a reverted call
+        let output = Encode::encode(&ink::MessageResult::<core::result::Result<u32, ContractError>>::Ok(Err(
+            ContractError::NotAuthorized,
+        )));
+        let decoded =
+            <core::result::Result<u32, ContractError> as DecodeMessageResult<DefaultAbi>>::decode_output(&output, true)
+                .unwrap();
+        let error = contract_result(decoded).unwrap_err();
+        assert_eq!(error.contract_error(), Some(&ContractError::NotAuthorized));
+        assert_eq!(error.to_string(), "contract error: NotAuthorized");
+
+        let error = contract_result::<u32>(Err(ink::LangError::CouldNotReadInput)).unwrap_err();
+        assert!(matches!(error, ClientError::Lang(ink::LangError::CouldNotReadInput)));
+        assert_eq!(error.contract_error(), None);
+    }
+
+    /// Compile-time check of the generated wrappers: message arguments in order, plus the
+    /// attached value for payable messages, and the contract's `Result` unwrapped
+    #[allow(dead_code)]
+    async fn wrappers_take_the_message_arguments(client: &mut EscrowClient) -> Result<()> {
+        let hackathon_id: u32 = client
+            .create_hackathon(String::from("Hack"), String::new(), Vec::new(), 1, 1_000)
+            .await?;
+        client.deposit_funds(hackathon_id, 500).await?;
+        let _: U256 = client.get_hackathon_balance(hackathon_id).await?;
+        let _: u32 = client.get_activity_count(hackathon_id).await?;
+        let _: MigrationStatus = client.migrate_storage(10).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod hackathon_escrow_factory {
+    use hackathonia::hackathon_escrow::{
+        ContractError, HackathonEscrowContractRef, HackathonSummary, Page, MAX_PAGE_SIZE,
+    };
+    use hackathonia::Escrow;
+    use ink::env::call::FromAddr;
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+    use ink::primitives::U256;
+    use ink::{ToAddr, H256};
+
+    /// ID of the only hackathon in a dedicated escrow contract
+    pub const DEDICATED_HACKATHON_ID: u32 = 1;
+
+    /// Escrow contract deployed for one hackathon
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout, Debug))]
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Deployment {
+        pub deployment_id: u32,
+        /// Address of the dedicated escrow contract
+        pub escrow: Address,
+        pub organizer: Address,
+        pub name: String,
+        /// Code the escrow was instantiated from
+        pub code_hash: H256,
+        pub created_at: u64,
+    }
+
+    #[ink(storage)]
+    pub struct HackathonEscrowFactory {
+        /// Administers the factory and every escrow it deploys
+        owner: Address,
+        /// Code hash new escrows are instantiated from
+        escrow_code_hash: H256,
+        /// Registry of deployments by ID
+        deployments: Mapping<u32, Deployment>,
+        /// Deployment ID of each escrow address
+        deployment_ids: Mapping<Address, u32>,
+        /// Deployment IDs by (organizer, position among the organizer's deployments)
+        organizer_deployments: Mapping<(Address, u32), u32>,
+        /// Number of deployments per organizer
+        organizer_deployment_counts: Mapping<Address, u32>,
+        /// Counter for deployment IDs
+        next_deployment_id: u32,
+    }
+
+    /// Factory errors
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum FactoryError {
+        NotAuthorized,
+        DeploymentNotFound,
+        InvalidPageSize,
+        /// The escrow code could not be instantiated
+        InstantiationFailed,
+        /// The escrow contract rejected the call
+        Escrow(ContractError),
+    }
+
+    impl From<ContractError> for FactoryError {
+        fn from(error: ContractError) -> Self {
+            FactoryError::Escrow(error)
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, FactoryError>;
+
+    impl HackathonEscrowFactory {
+        /// Constructor - Initialize the factory with the escrow code to deploy
+        #[ink(constructor)]
+        pub fn new(escrow_code_hash: H256) -> Self {
+            Self {
+                owner: Self::env().caller(),
+                escrow_code_hash,
+                deployments: Mapping::new(),
+                deployment_ids: Mapping::new(),
+                organizer_deployments: Mapping::new(),
+                organizer_deployment_counts: Mapping::new(),
+                next_deployment_id: 1,
+            }
+        }
+
+        /// Ensure the caller is the factory owner (internal function)
+        fn ensure_owner(&self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(FactoryError::NotAuthorized);
+            }
+            Ok(())
+        }
+
+        /// Validate a requested page size against `MAX_PAGE_SIZE` (internal function)
+        fn ensure_page_size(limit: u32) -> Result<()> {
+            if limit == 0 || limit > MAX_PAGE_SIZE {
+                return Err(FactoryError::InvalidPageSize);
+            }
+            Ok(())
+        }
+
+        /// Change the code hash new escrows are instantiated from (only owner)
+        #[ink(message)]
+        pub fn set_escrow_code_hash(&mut self, code_hash: H256) -> Result<()> {
+            self.ensure_owner()?;
+            self.escrow_code_hash = code_hash;
+            Ok(())
+        }
+
+        /// Get the code hash new escrows are instantiated from
+        #[ink(message)]
+        pub fn get_escrow_code_hash(&self) -> H256 {
+            self.escrow_code_hash
+        }
+
+        /// Get the factory owner
+        #[ink(message)]
+        pub fn get_owner(&self) -> Address {
+            self.owner
+        }
+
+        /// Deploy a dedicated escrow contract holding one hackathon organized by the caller.
+        ///
+        /// The transferred value funds the hackathon; the factory owner owns the new contract.
+        #[ink(message, payable)]
+        pub fn deploy_escrow(
+            &mut self,
+            name: String,
+            description: String,
+            multisig_addresses: Vec<Address>,
+            required_signatures: u32,
+        ) -> Result<u32> {
+            let organizer = self.env().caller();
+            let deployment_id = self.next_deployment_id;
+
+            // Salt by deployment so identical requests still get distinct addresses
+            let mut salt = [0u8; 32];
+            salt[..4].copy_from_slice(&deployment_id.to_le_bytes());
+
+            let escrow: HackathonEscrowContractRef = HackathonEscrowContractRef::new_dedicated(
+                self.owner,
+                organizer,
+                name.clone(),
+                description,
+                multisig_addresses,
+                required_signatures,
+            )
+            .code_hash(self.escrow_code_hash)
+            .endowment(self.env().transferred_value())
+            .salt_bytes(Some(salt))
+            .try_instantiate()
+            .map_err(|_| FactoryError::InstantiationFailed)?
+            .map_err(|_| FactoryError::InstantiationFailed)??;
+
+            let deployment = Deployment {
+                deployment_id,
+                escrow: escrow.to_addr(),
+                organizer,
+                name,
+                code_hash: self.escrow_code_hash,
+                created_at: self.env().block_timestamp(),
+            };
+            self.next_deployment_id += 1;
+            self.deployments.insert(deployment_id, &deployment);
+            self.deployment_ids.insert(deployment.escrow, &deployment_id);
+            let organized = self.get_organizer_deployment_count(organizer);
+            self.organizer_deployments.insert((organizer, organized), &deployment_id);
+            self.organizer_deployment_counts.insert(organizer, &organized.saturating_add(1));
+
+            Ok(deployment_id)
+        }
+
+        /// Get a deployment
+        #[ink(message)]
+        pub fn get_deployment(&self, deployment_id: u32) -> Result<Deployment> {
+            self.deployments
+                .get(deployment_id)
+                .ok_or(FactoryError::DeploymentNotFound)
+        }
+
+        /// Get the deployment of an escrow contract, if this factory deployed it
+        #[ink(message)]
+        pub fn find_deployment(&self, escrow: Address) -> Option<u32> {
+            self.deployment_ids.get(escrow)
+        }
+
+        /// List deployments in ID order.
+        ///
+        /// `cursor` is the deployment ID to start at (0 starts at the first deployment).
+        #[ink(message)]
+        pub fn list_deployments(&self, cursor: u32, limit: u32) -> Result<Page<Deployment>> {
+            Self::ensure_page_size(limit)?;
+
+            let mut deployment_id = cursor.max(1);
+            let mut items = Vec::new();
+            while deployment_id < self.next_deployment_id && items.len() < limit as usize {
+                if let Some(deployment) = self.deployments.get(deployment_id) {
+                    items.push(deployment);
+                }
+                deployment_id += 1;
+            }
+
+            Ok(Page {
+                items,
+                next_cursor: if deployment_id < self.next_deployment_id { Some(deployment_id) } else { None },
+            })
+        }
+
+        /// Get the IDs of deployments organized by an address, oldest first.
+        ///
+        /// `cursor` is the number of the organizer's deployments to skip.
+        #[ink(message)]
+        pub fn get_deployments_for_organizer(&self, organizer: Address, cursor: u32, limit: u32) -> Result<Page<u32>> {
+            Self::ensure_page_size(limit)?;
+
+            let count = self.get_organizer_deployment_count(organizer);
+            let end = cursor.saturating_add(limit).min(count);
+            let items = (cursor..end)
+                .filter_map(|position| self.organizer_deployments.get((organizer, position)))
+                .collect();
+
+            Ok(Page {
+                items,
+                next_cursor: if end < count { Some(end) } else { None },
+            })
+        }
+
+        /// Get the number of deployments organized by an address
+        #[ink(message)]
+        pub fn get_organizer_deployment_count(&self, organizer: Address) -> u32 {
+            self.organizer_deployment_counts.get(organizer).unwrap_or(0)
+        }
+
+        /// Get the number of deployments
+        #[ink(message)]
+        pub fn get_deployment_count(&self) -> u32 {
+            self.next_deployment_id - 1
+        }
+
+        /// Reference to a deployed escrow contract (internal function)
+        fn escrow(&self, deployment_id: u32) -> Result<HackathonEscrowContractRef> {
+            let deployment = self.get_deployment(deployment_id)?;
+            Ok(HackathonEscrowContractRef::from_addr(deployment.escrow))
+        }
+
+        /// Get the hackathon summary of a deployed escrow
+        #[ink(message)]
+        pub fn get_escrow_summary(&self, deployment_id: u32) -> Result<HackathonSummary> {
+            Ok(self.escrow(deployment_id)?.get_hackathon_summary(DEDICATED_HACKATHON_ID)?)
+        }
+
+        /// Get the hackathon balance of a deployed escrow
+        #[ink(message)]
+        pub fn get_escrow_balance(&self, deployment_id: u32) -> Result<U256> {
+            Ok(self.escrow(deployment_id)?.get_hackathon_balance(DEDICATED_HACKATHON_ID)?)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+
+        #[ink::test]
+        fn registry_rejects_unknown_deployments_and_bad_pages() {
+            let accounts = test::default_accounts();
+            test::set_caller(accounts.alice);
+            let mut factory = HackathonEscrowFactory::new(H256::repeat_byte(1));
+
+            assert_eq!(factory.get_owner(), accounts.alice);
+            assert_eq!(factory.get_deployment_count(), 0);
+            assert_eq!(factory.get_deployment(1), Err(FactoryError::DeploymentNotFound));
+            assert_eq!(factory.get_escrow_balance(1), Err(FactoryError::DeploymentNotFound));
+            assert_eq!(factory.find_deployment(accounts.bob), None);
+            assert_eq!(factory.list_deployments(0, 0).err(), Some(FactoryError::InvalidPageSize));
+            assert_eq!(factory.list_deployments(0, MAX_PAGE_SIZE + 1).err(), Some(FactoryError::InvalidPageSize));
+            assert_eq!(factory.list_deployments(0, 10).unwrap().items, Vec::new());
+            assert_eq!(
+                factory.get_deployments_for_organizer(accounts.bob, 0, 0).err(),
+                Some(FactoryError::InvalidPageSize)
+            );
+            assert_eq!(factory.get_deployments_for_organizer(accounts.bob, 0, 10).unwrap().items, Vec::<u32>::new());
+
+            test::set_caller(accounts.bob);
+            assert_eq!(factory.set_escrow_code_hash(H256::repeat_byte(2)), Err(FactoryError::NotAuthorized));
+            test::set_caller(accounts.alice);
+            factory.set_escrow_code_hash(H256::repeat_byte(2)).unwrap();
+            assert_eq!(factory.get_escrow_code_hash(), H256::repeat_byte(2));
+        }
+
+        #[ink::test]
+        fn deploys_one_escrow_per_hackathon() {
+            let accounts = test::default_accounts();
+            let code_hash = test::upload_code::<ink::env::DefaultEnvironment, HackathonEscrowContractRef>();
+            test::set_caller(accounts.alice);
+            let mut factory = HackathonEscrowFactory::new(code_hash);
+
+            test::set_caller(accounts.bob);
+            let first = factory
+                .deploy_escrow(String::from("Sponsor"), String::new(), vec![accounts.charlie], 1)
+                .unwrap();
+            let second = factory
+                .deploy_escrow(String::from("Sponsor"), String::new(), vec![accounts.charlie], 1)
+                .unwrap();
+            test::set_caller(accounts.django);
+            let third = factory.deploy_escrow(String::from("Other"), String::new(), vec![accounts.eve], 1).unwrap();
+
+            let deployment = factory.get_deployment(first).unwrap();
+            assert_eq!((deployment.organizer, deployment.code_hash), (accounts.bob, code_hash));
+            assert_ne!(deployment.escrow, factory.get_deployment(second).unwrap().escrow);
+            assert_eq!(factory.find_deployment(deployment.escrow), Some(first));
+            let organized = factory.get_deployments_for_organizer(accounts.bob, 0, 1).unwrap();
+            assert_eq!((organized.items, organized.next_cursor), (vec![first], Some(1)));
+            let organized = factory.get_deployments_for_organizer(accounts.bob, 1, 1).unwrap();
+            assert_eq!((organized.items, organized.next_cursor), (vec![second], None));
+            assert_eq!(factory.get_deployments_for_organizer(accounts.django, 0, MAX_PAGE_SIZE).unwrap().items, vec![third]);
+            assert_eq!(factory.get_organizer_deployment_count(accounts.bob), 2);
+            assert_eq!(factory.get_organizer_deployment_count(accounts.eve), 0);
+            assert_eq!(factory.get_deployment_count(), 3);
+
+            let page = factory.list_deployments(0, 2).unwrap();
+            assert_eq!(page.items.len(), 2);
+            assert_eq!(page.next_cursor, Some(3));
+            assert_eq!(factory.list_deployments(3, 2).unwrap().items[0].name, "Other");
+            assert_eq!(factory.get_escrow_balance(first), Ok(U256::zero()));
+            assert_eq!(factory.get_escrow_summary(third).unwrap().hackathon.organizer, accounts.django);
+        }
+
+        #[ink::test]
+        fn deployed_escrows_answer_through_the_escrow_trait() {
+            let accounts = test::default_accounts();
+            let code_hash = test::upload_code::<ink::env::DefaultEnvironment, HackathonEscrowContractRef>();
+            test::set_caller(accounts.alice);
+            let mut factory = HackathonEscrowFactory::new(code_hash);
+            let deployment_id = factory
+                .deploy_escrow(String::from("Sponsor"), String::new(), vec![accounts.charlie], 1)
+                .unwrap();
+
+            let mut escrow: ink::contract_ref_from_path!(Escrow) = factory.get_deployment(deployment_id).unwrap().escrow.into();
+            assert_eq!(escrow.get_hackathon(DEDICATED_HACKATHON_ID).unwrap().organizer, accounts.alice);
+            assert_eq!(escrow.get_hackathon(2).err(), Some(ContractError::HackathonNotFound));
+            assert_eq!(escrow.deposit_funds(DEDICATED_HACKATHON_ID), Err(ContractError::InvalidAmount));
+            assert_eq!(escrow.get_pending_requests(DEDICATED_HACKATHON_ID, 0, 10).unwrap().items, Vec::<u32>::new());
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use hackathonia::hackathon_escrow::HackathonEscrowContract;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn deploys_isolated_escrows<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+            let escrow_code = client
+                .upload("hackathonia", &ink_e2e::alice())
+                .submit()
+                .await
+                .expect("escrow upload failed")
+                .code_hash;
+
+            let mut constructor = HackathonEscrowFactoryRef::new(escrow_code);
+            let factory = client
+                .instantiate("hackathonia_factory", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("factory instantiation failed");
+            let mut call_builder = factory.call_builder::<HackathonEscrowFactory>();
+
+            let signer = ink_e2e::address_from_keypair::<AccountId>(&ink_e2e::charlie());
+            let deploy = call_builder.deploy_escrow(String::from("Sponsor"), String::new(), vec![signer], 1);
+            let first = client
+                .call(&ink_e2e::bob(), &deploy)
+                .value(1_000_000_000_000u128)
+                .submit()
+                .await
+                .expect("first deployment failed")
+                .return_value()
+                .expect("first deployment rejected");
+            let second = client
+                .call(&ink_e2e::bob(), &deploy)
+                .submit()
+                .await
+                .expect("second deployment failed")
+                .return_value()
+                .expect("second deployment rejected");
+
+            let get_deployment = call_builder.get_deployment(first);
+            let deployment = client
+                .call(&ink_e2e::bob(), &get_deployment)
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("deployment not registered");
+            assert_eq!(deployment.organizer, ink_e2e::address_from_keypair::<AccountId>(&ink_e2e::bob()));
+            let other = client
+                .call(&ink_e2e::bob(), &call_builder.get_deployment(second))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("deployment not registered");
+            assert_ne!(deployment.escrow, other.escrow);
+
+            // Each escrow answers the usual queries for its own hackathon and funds
+            let escrow = ink_e2e::create_call_builder::<HackathonEscrowContract>(deployment.escrow);
+            let hackathon = client
+                .call(&ink_e2e::bob(), &escrow.get_hackathon(DEDICATED_HACKATHON_ID))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("hackathon missing");
+            assert_eq!(hackathon.organizer, deployment.organizer);
+            let balance = client
+                .call(&ink_e2e::bob(), &call_builder.get_escrow_balance(first))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("balance query rejected");
+            assert_eq!(balance, hackathon.total_funds);
+            let other_balance = client
+                .call(&ink_e2e::bob(), &call_builder.get_escrow_balance(second))
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("balance query rejected");
+            assert_eq!(other_balance, U256::zero());
+
+            Ok(())
+        }
+    }
+}
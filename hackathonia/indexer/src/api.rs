@@ -0,0 +1,227 @@
+//! JSON HTTP API over the indexed data, following the server's `/api` conventions:
+//! `{ status: "success", data }` for items, with `meta` for paged lists, and
+//! `{ status: "error", message }` for failures.
+
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::store::{HackathonOrder, HackathonQuery, Listing, Store};
+
+/// Largest page the API returns
+const MAX_LIMIT: u32 = 100;
+
+type Shared = Arc<Mutex<Store>>;
+
+pub fn router(store: Shared) -> Router {
+    Router::new()
+        .route("/api/health", get(health))
+        .route("/api/hackathons", get(hackathons))
+        .route("/api/hackathons/{id}", get(hackathon))
+        .route("/api/hackathons/{id}/requests", get(requests))
+        .route("/api/hackathons/{id}/payouts", get(payouts))
+        .route("/api/hackathons/{id}/activity", get(activity))
+        .route("/api/requests/{id}", get(request))
+        .with_state(store)
+}
+
+/// Query parameters shared by list endpoints; unparsable values fall back to defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListParams {
+    page: Option<String>,
+    limit: Option<String>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+    is_active: Option<String>,
+    organizer: Option<String>,
+    search: Option<String>,
+    status: Option<String>,
+}
+
+impl ListParams {
+    fn page(&self) -> u32 {
+        self.page.as_deref().and_then(|page| page.parse().ok()).filter(|page| *page > 0).unwrap_or(1)
+    }
+
+    fn limit(&self) -> u32 {
+        self.limit
+            .as_deref()
+            .and_then(|limit| limit.parse().ok())
+            .filter(|limit| *limit > 0)
+            .map_or(10, |limit: u32| limit.min(MAX_LIMIT))
+    }
+}
+
+#[derive(Serialize)]
+struct Meta {
+    total: u64,
+    count: usize,
+    limit: u32,
+    page: u32,
+}
+
+enum ApiError {
+    NotFound(&'static str),
+    Server(&'static str),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (code, message) = match self {
+            ApiError::NotFound(what) => (StatusCode::NOT_FOUND, format!("{what} not found")),
+            ApiError::Server(message) => (StatusCode::INTERNAL_SERVER_ERROR, message.to_string()),
+        };
+        (code, Json(json!({ "status": "error", "message": message }))).into_response()
+    }
+}
+
+type ApiResult = Result<Json<serde_json::Value>, ApiError>;
+
+fn item<T: Serialize>(found: rusqlite::Result<Option<T>>, what: &'static str, failure: &'static str) -> ApiResult {
+    match found {
+        Ok(Some(data)) => Ok(Json(json!({ "status": "success", "data": data }))),
+        Ok(None) => Err(ApiError::NotFound(what)),
+        Err(error) => {
+            eprintln!("❌ {failure}: {error}");
+            Err(ApiError::Server(failure))
+        }
+    }
+}
+
+fn list<T: Serialize>(listing: rusqlite::Result<Listing<T>>, params: &ListParams, failure: &'static str) -> ApiResult {
+    let listing = listing.map_err(|error| {
+        eprintln!("❌ {failure}: {error}");
+        ApiError::Server(failure)
+    })?;
+    let meta = Meta { total: listing.total, count: listing.items.len(), limit: params.limit(), page: params.page() };
+    Ok(Json(json!({ "status": "success", "data": listing.items, "meta": meta })))
+}
+
+/// RFC 3339 UTC timestamp with milliseconds, as JavaScript's `toISOString` prints it
+fn iso_timestamp(millis: u64) -> String {
+    let (days, rest) = (millis / 86_400_000, millis % 86_400_000);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        rest / 3_600_000,
+        rest / 60_000 % 60,
+        rest / 1000 % 60,
+        rest % 1000
+    )
+}
+
+async fn health() -> Json<serde_json::Value> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u64);
+    Json(json!({ "status": "OK", "message": "Indexer is running", "timestamp": iso_timestamp(now) }))
+}
+
+async fn hackathons(State(store): State<Shared>, Query(params): Query<ListParams>) -> ApiResult {
+    let query = HackathonQuery {
+        is_active: params.is_active.as_deref().map(|value| value == "true"),
+        organizer: params.organizer.clone(),
+        search: params.search.clone().filter(|search| !search.is_empty()),
+        order: match params.sort_by.as_deref() {
+            Some("createdAt") => HackathonOrder::Created,
+            Some("totalDeposited") => HackathonOrder::Funds,
+            _ => HackathonOrder::Updated,
+        },
+        ascending: params.sort_order.as_deref() == Some("asc"),
+    };
+    let listing = store.lock().unwrap().hackathons(&query, params.page(), params.limit());
+    list(listing, &params, "Failed to fetch hackathons")
+}
+
+async fn hackathon(State(store): State<Shared>, Path(id): Path<u32>) -> ApiResult {
+    item(store.lock().unwrap().hackathon(id), "Hackathon", "Failed to fetch hackathon")
+}
+
+async fn requests(State(store): State<Shared>, Path(id): Path<u32>, Query(params): Query<ListParams>) -> ApiResult {
+    let listing = store.lock().unwrap().requests(id, params.status.as_deref(), params.page(), params.limit());
+    list(listing, &params, "Failed to fetch payout requests")
+}
+
+async fn payouts(State(store): State<Shared>, Path(id): Path<u32>, Query(params): Query<ListParams>) -> ApiResult {
+    let listing = store.lock().unwrap().payouts(id, params.page(), params.limit());
+    list(listing, &params, "Failed to fetch payouts")
+}
+
+async fn activity(State(store): State<Shared>, Path(id): Path<u32>, Query(params): Query<ListParams>) -> ApiResult {
+    let listing = store.lock().unwrap().activity(id, params.page(), params.limit());
+    list(listing, &params, "Failed to fetch activity")
+}
+
+async fn request(State(store): State<Shared>, Path(id): Path<u32>) -> ApiResult {
+    item(store.lock().unwrap().request(id), "Payout request", "Failed to fetch payout request")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::tests::{block, event};
+    use crate::store::Details;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use hackathonia::hackathon_escrow::ActivityType;
+    use tower::ServiceExt;
+
+    async fn get_json(router: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = router.clone().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn responses_use_the_server_envelope() {
+        let mut store = Store::open_in_memory().unwrap();
+        let name = Details { name: Some(String::from("Stadium")), ..Details::default() };
+        store
+            .insert_block(&block(1, "a"), &[
+                (event(1, 0, ActivityType::HackathonCreated, 1, 1, 1000, None), name.clone()),
+                (event(2, 0, ActivityType::HackathonCreated, 2, 1, 1000, None), name),
+                (event(1, 1, ActivityType::PayoutRequest, 4, 2, 300, Some(5)), Details::default()),
+            ])
+            .unwrap();
+        let router = router(Arc::new(Mutex::new(store)));
+
+        let (status, body) = get_json(&router, "/api/hackathons?limit=1&page=2&sortBy=createdAt&sortOrder=asc").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "success");
+        assert_eq!(body["meta"], json!({ "total": 2, "count": 1, "limit": 1, "page": 2 }));
+        assert_eq!(body["data"][0]["hackathonId"], 2);
+        assert_eq!(body["data"][0]["totalDeposited"], "1000");
+
+        let (_, body) = get_json(&router, "/api/hackathons/1/requests?status=pending").await;
+        assert_eq!(body["data"][0]["requestId"], 4);
+        assert_eq!(body["meta"]["total"], 1);
+        let (_, body) = get_json(&router, "/api/requests/4").await;
+        assert_eq!(body["data"]["amount"], "300");
+        assert_eq!(body["data"]["signatures"], json!([]));
+
+        let (status, body) = get_json(&router, "/api/hackathons/9").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body, json!({ "status": "error", "message": "Hackathon not found" }));
+
+        let (_, body) = get_json(&router, "/api/health").await;
+        assert_eq!(body["status"], "OK");
+        assert_eq!(iso_timestamp(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso_timestamp(1_709_251_199_999), "2024-02-29T23:59:59.999Z");
+    }
+}
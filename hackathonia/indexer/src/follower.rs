@@ -0,0 +1,284 @@
+//! Follows best blocks of a node and indexes the escrow contract's events.
+//!
+//! Blocks are matched to the store by hash. When the chain switches to another fork,
+//! the follower walks back along parent hashes to the last block both forks share,
+//! rolls the store back past it and indexes the new branch.
+
+use std::sync::{Arc, Mutex};
+
+use hackathonia::hackathon_escrow::{ActivityRecorded, ActivityType};
+use hackathonia_client::ink_e2e::events::ContractEmitted;
+use hackathonia_client::ink_e2e::subxt::{OnlineClient, PolkadotConfig};
+use hackathonia_client::ink_e2e::{self, subxt};
+use hackathonia_client::EscrowClient;
+use ink::primitives::{Address, H256};
+use ink::scale::Decode;
+
+use crate::store::{BlockRef, Details, Store};
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// Read access to the chain, as needed by the follower
+pub trait Chain {
+    /// Block with the given hash
+    async fn block(&mut self, hash: &str) -> Result<BlockRef, Error>;
+    /// Events the contract emitted in the given block, in order
+    async fn activity(&mut self, hash: &str) -> Result<Vec<ActivityRecorded>, Error>;
+    /// Contract state the event refers to but does not carry
+    async fn details(&mut self, event: &ActivityRecorded) -> Details;
+}
+
+/// Decode a contract event, if it is an `ActivityRecorded` event
+pub fn decode_activity(topics: &[H256], data: &[u8]) -> Option<ActivityRecorded> {
+    let signature = <ActivityRecorded as ink::env::Event>::SIGNATURE_TOPIC?;
+    if topics.first()?.as_bytes() != signature {
+        return None;
+    }
+    ActivityRecorded::decode(&mut &data[..]).ok()
+}
+
+pub struct Follower {
+    store: Arc<Mutex<Store>>,
+    /// Blocks below this number are never indexed
+    from_block: u64,
+}
+
+impl Follower {
+    pub fn new(store: Arc<Mutex<Store>>, from_block: u64) -> Self {
+        Self { store, from_block }
+    }
+
+    /// Bring the store in line with the chain up to `head`
+    pub async fn sync(&self, chain: &mut impl Chain, head: BlockRef) -> Result<(), Error> {
+        // Blocks of the new branch, newest first
+        let mut branch = Vec::new();
+        let mut current = head.clone();
+        while current.number >= self.from_block {
+            if self.store.lock().unwrap().block_hash(current.number)?.as_deref() == Some(current.hash.as_str()) {
+                break;
+            }
+            let parent_hash = current.parent_hash.clone();
+            let number = current.number;
+            branch.push(current);
+            if number == self.from_block {
+                break;
+            }
+            current = chain.block(&parent_hash).await?;
+        }
+
+        let first_new = branch.last().map_or(head.number + 1, |block| block.number);
+        {
+            let mut store = self.store.lock().unwrap();
+            if store.last_block()?.is_some_and(|last| last >= first_new) {
+                store.rollback_from(first_new)?;
+            }
+        }
+
+        for block in branch.into_iter().rev() {
+            let mut events = Vec::new();
+            for event in chain.activity(&block.hash).await? {
+                let details = chain.details(&event).await;
+                events.push((event, details));
+            }
+            self.store.lock().unwrap().insert_block(&block, &events)?;
+        }
+        Ok(())
+    }
+}
+
+/// Chain access through a node's RPC endpoint
+pub struct NodeChain {
+    api: OnlineClient<PolkadotConfig>,
+    contract: Address,
+    client: EscrowClient,
+}
+
+impl NodeChain {
+    pub async fn connect(url: &str, contract: Address) -> Result<Self, Error> {
+        let api = OnlineClient::<PolkadotConfig>::from_url(url).await?;
+        // Only read-only queries are made, so any account will do as the origin
+        let client = EscrowClient::connect(url, contract, ink_e2e::alice()).await?;
+        Ok(Self { api, contract, client })
+    }
+
+    /// Index every new best block until the subscription ends or fails
+    pub async fn follow(&mut self, follower: &Follower) -> Result<(), Error> {
+        let mut blocks = self.api.blocks().subscribe_best().await?;
+        while let Some(block) = blocks.next().await {
+            let block = block?;
+            let head = block_ref(block.number(), block.hash(), block.header().parent_hash);
+            follower.sync(self, head).await?;
+        }
+        Ok(())
+    }
+}
+
+type NodeHash = subxt::utils::H256;
+
+fn block_ref(number: u32, hash: NodeHash, parent_hash: NodeHash) -> BlockRef {
+    BlockRef {
+        number: number.into(),
+        hash: format!("0x{}", hex::encode(hash.as_bytes())),
+        parent_hash: format!("0x{}", hex::encode(parent_hash.as_bytes())),
+    }
+}
+
+fn parse_hash(hash: &str) -> Result<NodeHash, Error> {
+    let bytes: [u8; 32] = hex::decode(hash.trim_start_matches("0x"))?
+        .try_into()
+        .map_err(|_| format!("invalid block hash {hash}"))?;
+    Ok(NodeHash::from(bytes))
+}
+
+impl Chain for NodeChain {
+    async fn block(&mut self, hash: &str) -> Result<BlockRef, Error> {
+        let block = self.api.blocks().at(parse_hash(hash)?).await?;
+        Ok(block_ref(block.number(), block.hash(), block.header().parent_hash))
+    }
+
+    async fn activity(&mut self, hash: &str) -> Result<Vec<ActivityRecorded>, Error> {
+        let events = self.api.blocks().at(parse_hash(hash)?).await?.events().await?;
+        let mut activity = Vec::new();
+        for emitted in events.find::<ContractEmitted>() {
+            let emitted = emitted?;
+            if emitted.contract != self.contract {
+                continue;
+            }
+            activity.extend(decode_activity(&emitted.topics, &emitted.data));
+        }
+        Ok(activity)
+    }
+
+    /// Read from the contract's current state, so names reflect the latest metadata
+    /// rather than the one at the event's block; failures leave the details empty.
+    async fn details(&mut self, event: &ActivityRecorded) -> Details {
+        match event.item.activity_type {
+            ActivityType::HackathonCreated | ActivityType::MetadataUpdated => {
+                match self.client.get_hackathon(event.hackathon_id).await {
+                    Ok(hackathon) => Details {
+                        name: Some(hackathon.name),
+                        description: Some(hackathon.description),
+                        reason: None,
+                    },
+                    Err(_) => Details::default(),
+                }
+            }
+            ActivityType::PayoutRequest => Details {
+                reason: self.client.get_payout_request(event.item.id).await.ok().map(|request| request.reason),
+                ..Details::default()
+            },
+            _ => Details::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::store::tests::{block, event};
+    use ink::scale::Encode;
+
+    /// In-memory chain whose blocks carry encoded contract events
+    #[derive(Default)]
+    struct FakeChain {
+        blocks: HashMap<String, BlockRef>,
+        events: HashMap<String, Vec<Vec<u8>>>,
+    }
+
+    impl FakeChain {
+        /// Add blocks `from..=to` of a fork, each with one deposit into hackathon 1
+        fn extend(&mut self, fork: &str, from: u64, to: u64, parent: Option<&str>) -> BlockRef {
+            let mut head = None;
+            for number in from..=to {
+                let mut block = block(number, fork);
+                if number == from {
+                    if let Some(parent) = parent {
+                        block.parent_hash = parent.to_string();
+                    }
+                }
+                let amount = if fork == "a" { 100 } else { 10 };
+                let deposit = event(1, number as u32, ActivityType::FundsDeposited, 1, 1, amount, None);
+                self.events.insert(block.hash.clone(), vec![deposit.encode()]);
+                self.blocks.insert(block.hash.clone(), block.clone());
+                head = Some(block);
+            }
+            head.expect("at least one block")
+        }
+    }
+
+    impl Chain for FakeChain {
+        async fn block(&mut self, hash: &str) -> Result<BlockRef, Error> {
+            self.blocks.get(hash).cloned().ok_or_else(|| format!("unknown block {hash}").into())
+        }
+
+        async fn activity(&mut self, hash: &str) -> Result<Vec<ActivityRecorded>, Error> {
+            let topic = H256::from(<ActivityRecorded as ink::env::Event>::SIGNATURE_TOPIC.unwrap());
+            Ok(self.events[hash].iter().filter_map(|data| decode_activity(&[topic], data)).collect())
+        }
+
+        async fn details(&mut self, event: &ActivityRecorded) -> Details {
+            match event.item.activity_type {
+                ActivityType::HackathonCreated => Details { name: Some(String::from("Hack")), ..Details::default() },
+                _ => Details::default(),
+            }
+        }
+    }
+
+    fn deposited(store: &Arc<Mutex<Store>>) -> String {
+        store.lock().unwrap().hackathon(1).unwrap().unwrap().total_deposited
+    }
+
+    #[tokio::test]
+    async fn switches_forks_by_block_hash() {
+        let mut chain = FakeChain::default();
+        let genesis = block(0, "a");
+        let created = event(1, 0, ActivityType::HackathonCreated, 1, 1, 1000, None);
+        chain.events.insert(genesis.hash.clone(), vec![created.encode()]);
+        chain.blocks.insert(genesis.hash.clone(), genesis);
+        let a3 = chain.extend("a", 1, 3, None);
+
+        let store = Arc::new(Mutex::new(Store::open_in_memory().unwrap()));
+        let follower = Follower::new(store.clone(), 0);
+        follower.sync(&mut chain, a3.clone()).await.unwrap();
+        assert_eq!(deposited(&store), "1300");
+        assert_eq!(store.lock().unwrap().hackathon(1).unwrap().unwrap().name, "Hack");
+
+        // Same head again is a no-op
+        follower.sync(&mut chain, a3).await.unwrap();
+        assert_eq!(deposited(&store), "1300");
+
+        // A longer fork from block 1 replaces blocks 2 and 3
+        let b4 = chain.extend("b", 2, 4, Some("a1"));
+        follower.sync(&mut chain, b4).await.unwrap();
+        assert_eq!(deposited(&store), "1130");
+        assert_eq!(store.lock().unwrap().block_hash(3).unwrap().as_deref(), Some("b3"));
+        assert_eq!(store.lock().unwrap().last_block().unwrap(), Some(4));
+
+        // Going back to an indexed ancestor drops the blocks above it
+        let b2 = chain.blocks["b2"].clone();
+        follower.sync(&mut chain, b2).await.unwrap();
+        assert_eq!(store.lock().unwrap().last_block().unwrap(), Some(2));
+        assert_eq!(deposited(&store), "1110");
+    }
+
+    #[tokio::test]
+    async fn starts_at_the_configured_block() {
+        let mut chain = FakeChain::default();
+        let a3 = chain.extend("a", 1, 3, None);
+
+        let store = Arc::new(Mutex::new(Store::open_in_memory().unwrap()));
+        Follower::new(store.clone(), 2).sync(&mut chain, a3).await.unwrap();
+        let store = store.lock().unwrap();
+        assert_eq!(store.block_hash(1).unwrap(), None);
+        assert_eq!(store.activity(1, 1, 10).unwrap().total, 2);
+    }
+
+    #[test]
+    fn ignores_other_events() {
+        let deposit = event(1, 0, ActivityType::FundsDeposited, 1, 1, 5, None).encode();
+        assert!(decode_activity(&[H256::zero()], &deposit).is_none());
+        assert!(decode_activity(&[], &deposit).is_none());
+    }
+}
@@ -0,0 +1,106 @@
+//! `hackathonia-indexer`: follows the escrow contract's events into a local SQLite
+//! database and serves it as a JSON API.
+
+mod api;
+mod follower;
+mod store;
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use clap::Parser;
+use hackathonia_client::parse_address;
+use ink::primitives::Address;
+
+use follower::{Follower, NodeChain};
+use store::Store;
+
+/// Delay before reconnecting after the node connection fails
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Parser)]
+#[command(name = "hackathonia-indexer", version, about = "Index escrow contract events and serve them over HTTP")]
+struct Cli {
+    /// WebSocket URL of the node
+    #[arg(long, env = "HACKATHONIA_URL", default_value = "ws://127.0.0.1:9944")]
+    url: String,
+    /// Address of the deployed escrow contract
+    #[arg(long, env = "HACKATHONIA_CONTRACT", value_parser = parse_address)]
+    contract: Address,
+    /// SQLite database file, created if missing
+    #[arg(long, env = "HACKATHONIA_DATABASE", default_value = "hackathonia.db")]
+    database: PathBuf,
+    /// Address the HTTP API listens on
+    #[arg(long, env = "HACKATHONIA_LISTEN", default_value = "127.0.0.1:5000")]
+    listen: SocketAddr,
+    /// First block to index, usually the block the contract was deployed in
+    #[arg(long, default_value_t = 0)]
+    from_block: u64,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), follower::Error> {
+    let store = Arc::new(Mutex::new(Store::open(&cli.database)?));
+
+    let listener = tokio::net::TcpListener::bind(cli.listen).await?;
+    println!("🚀 API listening on http://{}", cli.listen);
+    let app = api::router(store.clone());
+    tokio::spawn(async move {
+        if let Err(error) = axum::serve(listener, app).await {
+            eprintln!("❌ API server stopped: {error}");
+        }
+    });
+
+    let follower = Follower::new(store, cli.from_block);
+    loop {
+        let result = match NodeChain::connect(&cli.url, cli.contract).await {
+            Ok(mut chain) => chain.follow(&follower).await,
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(()) => eprintln!("⚠️ Block subscription ended, reconnecting"),
+            Err(error) => eprintln!("❌ Indexing failed: {error}, reconnecting"),
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn parses_configuration() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from([
+            "hackathonia-indexer",
+            "--contract",
+            &"11".repeat(20),
+            "--listen",
+            "0.0.0.0:8080",
+            "--from-block",
+            "42",
+        ])
+        .unwrap();
+        assert_eq!(cli.contract, Address::from([0x11; 20]));
+        assert_eq!(cli.listen.port(), 8080);
+        assert_eq!(cli.from_block, 42);
+        assert_eq!(cli.database, PathBuf::from("hackathonia.db"));
+    }
+}
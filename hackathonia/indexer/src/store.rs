@@ -0,0 +1,885 @@
+//! SQLite storage for indexed events and the state derived from them.
+//!
+//! Raw events are the source of truth: derived tables are updated as each block is
+//! indexed, and the hackathons a rolled-back fork touched are rebuilt from their
+//! remaining events.
+
+use std::path::Path;
+
+use hackathonia::hackathon_escrow::ActivityRecorded;
+use hackathonia_client::hex_address;
+use ink::primitives::U256;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::Serialize;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS blocks (
+        number INTEGER PRIMARY KEY,
+        hash TEXT NOT NULL,
+        parent_hash TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS events (
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL,
+        hackathon_id INTEGER NOT NULL,
+        sequence INTEGER NOT NULL,
+        activity_type TEXT NOT NULL,
+        subject_id INTEGER NOT NULL,
+        actor TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        target TEXT,
+        timestamp INTEGER NOT NULL,
+        name TEXT,
+        description TEXT,
+        reason TEXT,
+        PRIMARY KEY (block_number, event_index)
+    );
+    CREATE INDEX IF NOT EXISTS events_by_hackathon ON events (hackathon_id, sequence);
+    CREATE TABLE IF NOT EXISTS hackathons (
+        hackathon_id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        description TEXT NOT NULL,
+        organizer TEXT NOT NULL,
+        total_deposited TEXT NOT NULL,
+        total_paid_out TEXT NOT NULL,
+        is_active INTEGER NOT NULL,
+        is_frozen INTEGER NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        created_block INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS payout_requests (
+        request_id INTEGER PRIMARY KEY,
+        hackathon_id INTEGER NOT NULL,
+        proposer TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        reason TEXT NOT NULL,
+        status TEXT NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS signatures (
+        request_id INTEGER NOT NULL,
+        signer TEXT NOT NULL,
+        signed_by TEXT NOT NULL,
+        signed_at INTEGER NOT NULL,
+        PRIMARY KEY (request_id, signer)
+    );
+    CREATE TABLE IF NOT EXISTS payouts (
+        request_id INTEGER PRIMARY KEY,
+        hackathon_id INTEGER NOT NULL,
+        recipient TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        executed_at INTEGER NOT NULL,
+        block_number INTEGER NOT NULL
+    );
+";
+
+/// Derived rows of one hackathon, deleted before its events are replayed
+const DERIVED_ROWS: [&str; 4] = [
+    "DELETE FROM signatures WHERE request_id IN (SELECT request_id FROM payout_requests WHERE hackathon_id = ?1)",
+    "DELETE FROM payouts WHERE hackathon_id = ?1",
+    "DELETE FROM payout_requests WHERE hackathon_id = ?1",
+    "DELETE FROM hackathons WHERE hackathon_id = ?1",
+];
+
+/// Block as seen on chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockRef {
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+}
+
+/// Contract state read when an event is indexed, which the event itself does not carry
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Details {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// One indexed event, as stored
+struct EventRow {
+    block_number: u64,
+    hackathon_id: u32,
+    activity_type: String,
+    subject_id: u32,
+    actor: String,
+    amount: String,
+    target: Option<String>,
+    timestamp: u64,
+    details: Details,
+}
+
+impl EventRow {
+    fn new(block_number: u64, event: &ActivityRecorded, details: Details) -> Self {
+        Self {
+            block_number,
+            hackathon_id: event.hackathon_id,
+            activity_type: format!("{:?}", event.item.activity_type),
+            subject_id: event.item.id,
+            actor: hex_address(&event.item.actor),
+            amount: event.item.amount.to_string(),
+            target: event.item.target.as_ref().map(hex_address),
+            timestamp: event.item.timestamp,
+            details,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HackathonRow {
+    pub hackathon_id: u32,
+    pub name: String,
+    pub description: String,
+    pub organizer: String,
+    pub total_deposited: String,
+    pub total_paid_out: String,
+    pub is_active: bool,
+    pub is_frozen: bool,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub created_block: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestRow {
+    pub request_id: u32,
+    pub hackathon_id: u32,
+    pub proposer: String,
+    pub recipient: String,
+    pub amount: String,
+    pub reason: String,
+    pub status: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub signature_count: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureRow {
+    pub signer: String,
+    /// Account that submitted the signature; differs from `signer` for delegates
+    pub signed_by: String,
+    pub signed_at: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestDetail {
+    #[serde(flatten)]
+    pub request: RequestRow,
+    pub signatures: Vec<SignatureRow>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutRow {
+    pub request_id: u32,
+    pub hackathon_id: u32,
+    pub recipient: String,
+    pub amount: String,
+    pub executed_at: u64,
+    pub block_number: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRow {
+    pub block_number: u64,
+    pub sequence: u32,
+    pub activity_type: String,
+    pub subject_id: u32,
+    pub actor: String,
+    pub amount: String,
+    pub target: Option<String>,
+    pub timestamp: u64,
+}
+
+/// Column the hackathon list is ordered by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HackathonOrder {
+    #[default]
+    Updated,
+    Created,
+    /// Funds deposited, net of refunds; the contract's `list_hackathons` only orders by ID
+    Funds,
+}
+
+/// Filters and ordering for the hackathon list
+#[derive(Debug, Default)]
+pub struct HackathonQuery {
+    pub is_active: Option<bool>,
+    pub organizer: Option<String>,
+    pub search: Option<String>,
+    pub order: HackathonOrder,
+    pub ascending: bool,
+}
+
+/// One page of a list, with the total number of matches
+#[derive(Debug)]
+pub struct Listing<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Hash of the indexed block at `number`
+    pub fn block_hash(&self, number: u64) -> rusqlite::Result<Option<String>> {
+        self.conn
+            .query_row("SELECT hash FROM blocks WHERE number = ?1", [number], |row| row.get(0))
+            .optional()
+    }
+
+    /// Highest indexed block number
+    pub fn last_block(&self) -> rusqlite::Result<Option<u64>> {
+        self.conn.query_row("SELECT MAX(number) FROM blocks", [], |row| row.get(0))
+    }
+
+    /// Store a block with its contract events and apply them to the derived tables
+    pub fn insert_block(&mut self, block: &BlockRef, events: &[(ActivityRecorded, Details)]) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO blocks (number, hash, parent_hash) VALUES (?1, ?2, ?3)",
+            params![block.number, block.hash, block.parent_hash],
+        )?;
+        for (index, (event, details)) in events.iter().enumerate() {
+            let row = EventRow::new(block.number, event, details.clone());
+            tx.execute(
+                "INSERT INTO events (block_number, event_index, hackathon_id, sequence, activity_type, subject_id,
+                     actor, amount, target, timestamp, name, description, reason)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+                params![
+                    row.block_number,
+                    index as u32,
+                    row.hackathon_id,
+                    event.sequence,
+                    row.activity_type,
+                    row.subject_id,
+                    row.actor,
+                    row.amount,
+                    row.target,
+                    row.timestamp,
+                    row.details.name,
+                    row.details.description,
+                    row.details.reason,
+                ],
+            )?;
+            apply(&tx, &row)?;
+        }
+        tx.commit()
+    }
+
+    /// Forget every block from `number` on and rebuild the hackathons those blocks touched
+    /// from their remaining events
+    pub fn rollback_from(&mut self, number: u64) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        let affected = {
+            let mut statement = tx.prepare("SELECT DISTINCT hackathon_id FROM events WHERE block_number >= ?1")?;
+            let ids = statement.query_map([number], |row| row.get::<_, u32>(0))?;
+            ids.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+        tx.execute("DELETE FROM blocks WHERE number >= ?1", [number])?;
+        tx.execute("DELETE FROM events WHERE block_number >= ?1", [number])?;
+
+        for hackathon_id in affected {
+            for statement in DERIVED_ROWS {
+                tx.execute(statement, [hackathon_id])?;
+            }
+            let rows = {
+                let mut statement = tx.prepare(
+                    "SELECT block_number, hackathon_id, activity_type, subject_id, actor, amount, target, timestamp,
+                         name, description, reason
+                     FROM events WHERE hackathon_id = ?1 ORDER BY block_number, event_index",
+                )?;
+                let rows = statement.query_map([hackathon_id], |row| {
+                    Ok(EventRow {
+                        block_number: row.get(0)?,
+                        hackathon_id: row.get(1)?,
+                        activity_type: row.get(2)?,
+                        subject_id: row.get(3)?,
+                        actor: row.get(4)?,
+                        amount: row.get(5)?,
+                        target: row.get(6)?,
+                        timestamp: row.get(7)?,
+                        details: Details { name: row.get(8)?, description: row.get(9)?, reason: row.get(10)? },
+                    })
+                })?;
+                rows.collect::<rusqlite::Result<Vec<_>>>()?
+            };
+            for row in &rows {
+                apply(&tx, row)?;
+            }
+        }
+        tx.commit()
+    }
+
+    pub fn hackathons(&self, query: &HackathonQuery, page: u32, limit: u32) -> rusqlite::Result<Listing<HackathonRow>> {
+        let search = query.search.as_ref().map(|term| format!("%{term}%"));
+        let filter = "WHERE (?1 IS NULL OR is_active = ?1)
+                        AND (?2 IS NULL OR organizer = lower(?2))
+                        AND (?3 IS NULL OR name LIKE ?3)";
+        let total = self.conn.query_row(
+            &format!("SELECT COUNT(*) FROM hackathons {filter}"),
+            params![query.is_active, query.organizer, search],
+            |row| row.get(0),
+        )?;
+
+        let order = if query.ascending { "ASC" } else { "DESC" };
+        let sort = match query.order {
+            HackathonOrder::Updated => format!("updated_at {order}"),
+            HackathonOrder::Created => format!("created_at {order}"),
+            // Amounts are decimal strings without leading zeros: longer is larger
+            HackathonOrder::Funds => format!("length(total_deposited) {order}, total_deposited {order}"),
+        };
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT {HACKATHON_COLUMNS} FROM hackathons {filter}
+             ORDER BY {sort}, hackathon_id {order} LIMIT ?4 OFFSET ?5"
+        ))?;
+        let items = statement
+            .query_map(
+                params![query.is_active, query.organizer, search, limit, offset(page, limit)],
+                hackathon_row,
+            )?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Listing { items, total })
+    }
+
+    pub fn hackathon(&self, hackathon_id: u32) -> rusqlite::Result<Option<HackathonRow>> {
+        self.conn
+            .query_row(
+                &format!("SELECT {HACKATHON_COLUMNS} FROM hackathons WHERE hackathon_id = ?1"),
+                [hackathon_id],
+                hackathon_row,
+            )
+            .optional()
+    }
+
+    /// Payout requests of a hackathon, newest first, optionally with one status
+    pub fn requests(
+        &self,
+        hackathon_id: u32,
+        status: Option<&str>,
+        page: u32,
+        limit: u32,
+    ) -> rusqlite::Result<Listing<RequestRow>> {
+        let filter = "WHERE hackathon_id = ?1 AND (?2 IS NULL OR status = ?2)";
+        let total = self.conn.query_row(
+            &format!("SELECT COUNT(*) FROM payout_requests {filter}"),
+            params![hackathon_id, status],
+            |row| row.get(0),
+        )?;
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT {REQUEST_COLUMNS} FROM payout_requests {filter} ORDER BY request_id DESC LIMIT ?3 OFFSET ?4"
+        ))?;
+        let items = statement
+            .query_map(params![hackathon_id, status, limit, offset(page, limit)], request_row)?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Listing { items, total })
+    }
+
+    pub fn request(&self, request_id: u32) -> rusqlite::Result<Option<RequestDetail>> {
+        let Some(request) = self
+            .conn
+            .query_row(
+                &format!("SELECT {REQUEST_COLUMNS} FROM payout_requests WHERE request_id = ?1"),
+                [request_id],
+                request_row,
+            )
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let mut statement = self.conn.prepare(
+            "SELECT signer, signed_by, signed_at FROM signatures WHERE request_id = ?1 ORDER BY signed_at, signer",
+        )?;
+        let signatures = statement
+            .query_map([request_id], |row| {
+                Ok(SignatureRow { signer: row.get(0)?, signed_by: row.get(1)?, signed_at: row.get(2)? })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Some(RequestDetail { request, signatures }))
+    }
+
+    /// Executed payouts of a hackathon, newest first
+    pub fn payouts(&self, hackathon_id: u32, page: u32, limit: u32) -> rusqlite::Result<Listing<PayoutRow>> {
+        let total = self.conn.query_row(
+            "SELECT COUNT(*) FROM payouts WHERE hackathon_id = ?1",
+            [hackathon_id],
+            |row| row.get(0),
+        )?;
+        let mut statement = self.conn.prepare(
+            "SELECT request_id, hackathon_id, recipient, amount, executed_at, block_number
+             FROM payouts WHERE hackathon_id = ?1 ORDER BY block_number DESC, request_id DESC LIMIT ?2 OFFSET ?3",
+        )?;
+        let items = statement
+            .query_map(params![hackathon_id, limit, offset(page, limit)], |row| {
+                Ok(PayoutRow {
+                    request_id: row.get(0)?,
+                    hackathon_id: row.get(1)?,
+                    recipient: row.get(2)?,
+                    amount: row.get(3)?,
+                    executed_at: row.get(4)?,
+                    block_number: row.get(5)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Listing { items, total })
+    }
+
+    /// Activity log of a hackathon, newest first
+    pub fn activity(&self, hackathon_id: u32, page: u32, limit: u32) -> rusqlite::Result<Listing<ActivityRow>> {
+        let total = self.conn.query_row(
+            "SELECT COUNT(*) FROM events WHERE hackathon_id = ?1",
+            [hackathon_id],
+            |row| row.get(0),
+        )?;
+        let mut statement = self.conn.prepare(
+            "SELECT block_number, sequence, activity_type, subject_id, actor, amount, target, timestamp
+             FROM events WHERE hackathon_id = ?1 ORDER BY sequence DESC LIMIT ?2 OFFSET ?3",
+        )?;
+        let items = statement
+            .query_map(params![hackathon_id, limit, offset(page, limit)], |row| {
+                Ok(ActivityRow {
+                    block_number: row.get(0)?,
+                    sequence: row.get(1)?,
+                    activity_type: row.get(2)?,
+                    subject_id: row.get(3)?,
+                    actor: row.get(4)?,
+                    amount: row.get(5)?,
+                    target: row.get(6)?,
+                    timestamp: row.get(7)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(Listing { items, total })
+    }
+}
+
+const HACKATHON_COLUMNS: &str = "hackathon_id, name, description, organizer, total_deposited, total_paid_out,
+    is_active, is_frozen, created_at, updated_at, created_block";
+
+fn hackathon_row(row: &rusqlite::Row) -> rusqlite::Result<HackathonRow> {
+    Ok(HackathonRow {
+        hackathon_id: row.get(0)?,
+        name: row.get(1)?,
+        description: row.get(2)?,
+        organizer: row.get(3)?,
+        total_deposited: row.get(4)?,
+        total_paid_out: row.get(5)?,
+        is_active: row.get(6)?,
+        is_frozen: row.get(7)?,
+        created_at: row.get(8)?,
+        updated_at: row.get(9)?,
+        created_block: row.get(10)?,
+    })
+}
+
+const REQUEST_COLUMNS: &str = "request_id, hackathon_id, proposer, recipient, amount, reason, status, created_at,
+    updated_at, (SELECT COUNT(*) FROM signatures WHERE signatures.request_id = payout_requests.request_id)";
+
+fn request_row(row: &rusqlite::Row) -> rusqlite::Result<RequestRow> {
+    Ok(RequestRow {
+        request_id: row.get(0)?,
+        hackathon_id: row.get(1)?,
+        proposer: row.get(2)?,
+        recipient: row.get(3)?,
+        amount: row.get(4)?,
+        reason: row.get(5)?,
+        status: row.get(6)?,
+        created_at: row.get(7)?,
+        updated_at: row.get(8)?,
+        signature_count: row.get(9)?,
+    })
+}
+
+fn offset(page: u32, limit: u32) -> u64 {
+    u64::from(page.saturating_sub(1)) * u64::from(limit)
+}
+
+/// Add `amount` to a decimal column of one hackathon
+fn add_amount(tx: &Transaction, column: &str, hackathon_id: u32, amount: &str) -> rusqlite::Result<()> {
+    adjust_amount(tx, column, hackathon_id, amount, U256::saturating_add)
+}
+
+/// Subtract `amount` from a decimal column of one hackathon
+fn subtract_amount(tx: &Transaction, column: &str, hackathon_id: u32, amount: &str) -> rusqlite::Result<()> {
+    adjust_amount(tx, column, hackathon_id, amount, U256::saturating_sub)
+}
+
+/// Combine a decimal column of one hackathon with `amount`
+fn adjust_amount(
+    tx: &Transaction,
+    column: &str,
+    hackathon_id: u32,
+    amount: &str,
+    combine: fn(U256, U256) -> U256,
+) -> rusqlite::Result<()> {
+    let current: Option<String> = tx
+        .query_row(&format!("SELECT {column} FROM hackathons WHERE hackathon_id = ?1"), [hackathon_id], |row| {
+            row.get(0)
+        })
+        .optional()?;
+    let Some(current) = current else {
+        return Ok(());
+    };
+    let parse = |value: &str| U256::from_dec_str(value).unwrap_or_default();
+    let total = combine(parse(&current), parse(amount));
+    tx.execute(
+        &format!("UPDATE hackathons SET {column} = ?1 WHERE hackathon_id = ?2"),
+        params![total.to_string(), hackathon_id],
+    )?;
+    Ok(())
+}
+
+/// Apply one event to the derived tables
+fn apply(tx: &Transaction, event: &EventRow) -> rusqlite::Result<()> {
+    let hackathon_id = event.hackathon_id;
+    let request_id = event.subject_id;
+    let set_request_status = |status: &str| {
+        tx.execute(
+            "UPDATE payout_requests SET status = ?1, updated_at = ?2 WHERE request_id = ?3",
+            params![status, event.timestamp, request_id],
+        )
+    };
+
+    match event.activity_type.as_str() {
+        "HackathonCreated" => {
+            tx.execute(
+                "INSERT OR REPLACE INTO hackathons (hackathon_id, name, description, organizer, total_deposited,
+                     total_paid_out, is_active, is_frozen, created_at, updated_at, created_block)
+                 VALUES (?1, ?2, ?3, ?4, ?5, '0', 1, 0, ?6, ?6, ?7)",
+                params![
+                    hackathon_id,
+                    event.details.name.clone().unwrap_or_default(),
+                    event.details.description.clone().unwrap_or_default(),
+                    // The organizer is the target; the actor differs for factory deployments
+                    event.target.as_deref().unwrap_or(&event.actor),
+                    event.amount,
+                    event.timestamp,
+                    event.block_number,
+                ],
+            )?;
+        }
+        // Slashed bonds stay in the escrow and refunds leave it, as `get_hackathon_balance` sees them
+        "FundsDeposited" | "SurplusAllocated" | "BondSlashed" => {
+            add_amount(tx, "total_deposited", hackathon_id, &event.amount)?
+        }
+        "FundsRefunded" => subtract_amount(tx, "total_deposited", hackathon_id, &event.amount)?,
+        "MetadataUpdated" => {
+            tx.execute(
+                "UPDATE hackathons SET name = COALESCE(?1, name), description = COALESCE(?2, description)
+                 WHERE hackathon_id = ?3",
+                params![event.details.name, event.details.description, hackathon_id],
+            )?;
+        }
+        "HackathonDeactivated" => {
+            tx.execute("UPDATE hackathons SET is_active = 0 WHERE hackathon_id = ?1", [hackathon_id])?;
+        }
+        "HackathonFrozen" | "HackathonUnfrozen" => {
+            let frozen = event.activity_type == "HackathonFrozen";
+            tx.execute(
+                "UPDATE hackathons SET is_frozen = ?1 WHERE hackathon_id = ?2",
+                params![frozen, hackathon_id],
+            )?;
+        }
+        "PayoutRequest" => {
+            tx.execute(
+                "INSERT OR REPLACE INTO payout_requests (request_id, hackathon_id, proposer, recipient, amount, reason,
+                     status, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'pending', ?7, ?7)",
+                params![
+                    request_id,
+                    hackathon_id,
+                    event.actor,
+                    event.target.clone().unwrap_or_default(),
+                    event.amount,
+                    event.details.reason.clone().unwrap_or_default(),
+                    event.timestamp,
+                ],
+            )?;
+        }
+        "PayoutSigned" => {
+            tx.execute(
+                "INSERT OR IGNORE INTO signatures (request_id, signer, signed_by, signed_at) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    request_id,
+                    event.target.as_deref().unwrap_or(&event.actor),
+                    event.actor,
+                    event.timestamp,
+                ],
+            )?;
+        }
+        "PayoutScheduled" | "DisputeResolved" => {
+            set_request_status("scheduled")?;
+        }
+        "PayoutDisputed" => {
+            set_request_status("disputed")?;
+        }
+        "PayoutCancelled" => {
+            set_request_status("cancelled")?;
+        }
+        "PayoutExecuted" => {
+            set_request_status("executed")?;
+            tx.execute(
+                "INSERT OR REPLACE INTO payouts (request_id, hackathon_id, recipient, amount, executed_at, block_number)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    request_id,
+                    hackathon_id,
+                    event.target.clone().unwrap_or_default(),
+                    event.amount,
+                    event.timestamp,
+                    event.block_number,
+                ],
+            )?;
+            add_amount(tx, "total_paid_out", hackathon_id, &event.amount)?;
+        }
+        _ => {}
+    }
+
+    tx.execute(
+        "UPDATE hackathons SET updated_at = MAX(updated_at, ?1) WHERE hackathon_id = ?2",
+        params![event.timestamp, hackathon_id],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use hackathonia::hackathon_escrow::{ActivityItem, ActivityType};
+    use ink::primitives::Address;
+
+    pub(crate) fn account(byte: u8) -> Address {
+        Address::from([byte; 20])
+    }
+
+    pub(crate) fn event(
+        hackathon_id: u32,
+        sequence: u32,
+        activity_type: ActivityType,
+        id: u32,
+        actor: u8,
+        amount: u32,
+        target: Option<u8>,
+    ) -> ActivityRecorded {
+        ActivityRecorded {
+            hackathon_id,
+            sequence,
+            item: ActivityItem {
+                activity_type,
+                id,
+                actor: account(actor),
+                amount: U256::from(amount),
+                target: target.map(account),
+                timestamp: u64::from(sequence) * 1000,
+            },
+        }
+    }
+
+    pub(crate) fn block(number: u64, fork: &str) -> BlockRef {
+        BlockRef {
+            number,
+            hash: format!("{fork}{number}"),
+            parent_hash: if number == 0 { String::new() } else { format!("{fork}{}", number - 1) },
+        }
+    }
+
+    fn named(name: &str) -> Details {
+        Details { name: Some(name.to_string()), description: Some(String::from("Build")), reason: None }
+    }
+
+    /// Hackathon 1 with a 300 payout to account 5, executed in block 3
+    fn populated() -> Store {
+        let mut store = Store::open_in_memory().unwrap();
+        store
+            .insert_block(&block(1, "a"), &[
+                (event(1, 0, ActivityType::HackathonCreated, 1, 1, 1000, None), named("Stadium")),
+                (event(1, 1, ActivityType::FundsDeposited, 1, 1, 500, None), Details::default()),
+            ])
+            .unwrap();
+        let reason = Details { reason: Some(String::from("Winner")), ..Details::default() };
+        store
+            .insert_block(&block(2, "a"), &[
+                (event(1, 2, ActivityType::PayoutRequest, 7, 2, 300, Some(5)), reason),
+                (event(1, 3, ActivityType::PayoutSigned, 7, 2, 300, Some(2)), Details::default()),
+            ])
+            .unwrap();
+        store
+            .insert_block(&block(3, "a"), &[
+                (event(1, 4, ActivityType::PayoutSigned, 7, 9, 300, Some(3)), Details::default()),
+                (event(1, 5, ActivityType::PayoutExecuted, 7, 9, 300, Some(5)), Details::default()),
+            ])
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn events_build_hackathons_requests_signatures_and_payouts() {
+        let store = populated();
+        assert_eq!(store.last_block().unwrap(), Some(3));
+        assert_eq!(store.block_hash(2).unwrap().as_deref(), Some("a2"));
+
+        let hackathon = store.hackathon(1).unwrap().unwrap();
+        assert_eq!(hackathon.name, "Stadium");
+        assert_eq!(hackathon.organizer, hex_address(&account(1)));
+        assert_eq!((hackathon.total_deposited.as_str(), hackathon.total_paid_out.as_str()), ("1500", "300"));
+        assert_eq!(hackathon.updated_at, 5000);
+
+        let request = store.request(7).unwrap().unwrap();
+        assert_eq!(request.request.status, "executed");
+        assert_eq!(request.request.reason, "Winner");
+        assert_eq!(request.request.recipient, hex_address(&account(5)));
+        assert_eq!(request.signatures.len(), 2);
+        // Account 9 signed on behalf of account 3
+        assert_eq!(request.signatures[1].signer, hex_address(&account(3)));
+        assert_eq!(request.signatures[1].signed_by, hex_address(&account(9)));
+
+        let payouts = store.payouts(1, 1, 10).unwrap();
+        assert_eq!(payouts.total, 1);
+        assert_eq!((payouts.items[0].amount.as_str(), payouts.items[0].block_number), ("300", 3));
+
+        let activity = store.activity(1, 1, 4).unwrap();
+        assert_eq!(activity.total, 6);
+        let sequences: Vec<u32> = activity.items.iter().map(|item| item.sequence).collect();
+        assert_eq!(sequences, vec![5, 4, 3, 2]);
+        assert_eq!(store.activity(1, 2, 4).unwrap().items.len(), 2);
+    }
+
+    #[test]
+    fn refunds_reduce_the_deposited_total() {
+        let mut store = populated();
+        store
+            .insert_block(&block(4, "a"), &[
+                (event(1, 6, ActivityType::HackathonDeactivated, 1, 1, 0, None), Details::default()),
+                (event(1, 7, ActivityType::FundsRefunded, 1, 8, 200, Some(1)), Details::default()),
+            ])
+            .unwrap();
+
+        let hackathon = store.hackathon(1).unwrap().unwrap();
+        assert_eq!((hackathon.total_deposited.as_str(), hackathon.total_paid_out.as_str()), ("1300", "300"));
+    }
+
+    #[test]
+    fn slashed_dispute_bonds_join_the_deposited_total() {
+        let mut store = populated();
+        store
+            .insert_block(&block(4, "a"), &[
+                (event(1, 6, ActivityType::DisputeResolved, 7, 2, 50, Some(6)), Details::default()),
+                (event(1, 7, ActivityType::BondSlashed, 7, 2, 50, Some(6)), Details::default()),
+            ])
+            .unwrap();
+
+        let hackathon = store.hackathon(1).unwrap().unwrap();
+        assert_eq!(hackathon.total_deposited, "1550");
+    }
+
+    #[test]
+    fn rollback_rebuilds_state_from_remaining_events() {
+        let mut store = populated();
+        store.rollback_from(3).unwrap();
+
+        assert_eq!(store.last_block().unwrap(), Some(2));
+        assert_eq!(store.block_hash(3).unwrap(), None);
+        let hackathon = store.hackathon(1).unwrap().unwrap();
+        assert_eq!((hackathon.total_deposited.as_str(), hackathon.total_paid_out.as_str()), ("1500", "0"));
+        let request = store.request(7).unwrap().unwrap();
+        assert_eq!((request.request.status.as_str(), request.signatures.len()), ("pending", 1));
+        assert_eq!(store.payouts(1, 1, 10).unwrap().total, 0);
+
+        store.rollback_from(0).unwrap();
+        assert_eq!(store.last_block().unwrap(), None);
+        assert!(store.hackathon(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn rollback_only_rebuilds_the_hackathons_it_touched() {
+        let mut store = populated();
+        store
+            .insert_block(&block(4, "a"), &[(event(2, 0, ActivityType::HackathonCreated, 2, 4, 80, None), named("Harbor"))])
+            .unwrap();
+        store
+            .insert_block(&block(5, "a"), &[(event(2, 1, ActivityType::FundsDeposited, 2, 4, 20, None), Details::default())])
+            .unwrap();
+        // Derived rows of untouched hackathons are left alone rather than replayed
+        store.conn.execute("UPDATE hackathons SET updated_at = 99 WHERE hackathon_id = 1", []).unwrap();
+
+        store.rollback_from(5).unwrap();
+
+        assert_eq!(store.hackathon(1).unwrap().unwrap().updated_at, 99);
+        assert_eq!(store.hackathon(2).unwrap().unwrap().total_deposited, "80");
+        assert_eq!(store.request(7).unwrap().unwrap().signatures.len(), 2);
+    }
+
+    #[test]
+    fn hackathons_are_filtered_sorted_and_paged() {
+        let mut store = Store::open_in_memory().unwrap();
+        store
+            .insert_block(&block(1, "a"), &[
+                (event(1, 0, ActivityType::HackathonCreated, 1, 1, 10, None), named("Alpha")),
+                (event(2, 0, ActivityType::HackathonCreated, 2, 2, 20, None), named("Beta")),
+                (event(3, 0, ActivityType::HackathonCreated, 3, 1, 30, None), named("Gamma")),
+            ])
+            .unwrap();
+        store
+            .insert_block(&block(2, "a"), &[
+                (event(2, 1, ActivityType::HackathonDeactivated, 2, 2, 0, None), Details::default()),
+                (event(3, 2, ActivityType::FundsDeposited, 3, 1, 5, None), Details::default()),
+            ])
+            .unwrap();
+
+        let ids = |listing: Listing<HackathonRow>| -> Vec<u32> {
+            listing.items.iter().map(|row| row.hackathon_id).collect()
+        };
+        assert_eq!(ids(store.hackathons(&HackathonQuery::default(), 1, 10).unwrap()), vec![3, 2, 1]);
+
+        let active = HackathonQuery { is_active: Some(true), ..HackathonQuery::default() };
+        assert_eq!(store.hackathons(&active, 1, 10).unwrap().total, 2);
+
+        let by_organizer = HackathonQuery {
+            organizer: Some(hex_address(&account(1)).to_uppercase().replace("0X", "0x")),
+            order: HackathonOrder::Created,
+            ascending: true,
+            ..HackathonQuery::default()
+        };
+        assert_eq!(ids(store.hackathons(&by_organizer, 1, 10).unwrap()), vec![1, 3]);
+
+        let search = HackathonQuery { search: Some(String::from("amm")), ..HackathonQuery::default() };
+        assert_eq!(ids(store.hackathons(&search, 1, 10).unwrap()), vec![3]);
+
+        let second_page = store.hackathons(&HackathonQuery::default(), 2, 2).unwrap();
+        assert_eq!((ids(second_page), 3), (vec![1], 3));
+
+        // 100 sorts above 35 although it is smaller as a string
+        store
+            .insert_block(&block(3, "a"), &[(event(1, 1, ActivityType::FundsDeposited, 1, 1, 90, None), Details::default())])
+            .unwrap();
+        let by_funds = HackathonQuery { order: HackathonOrder::Funds, ..HackathonQuery::default() };
+        assert_eq!(ids(store.hackathons(&by_funds, 1, 10).unwrap()), vec![1, 3, 2]);
+    }
+}
@@ -0,0 +1,294 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Reference `PayoutReceiver` that records the payout notifications it gets.
+///
+/// It is used to test payout callbacks and as a starting point for team DAOs or splitters.
+/// A production receiver should also check that `caller()` is the escrow it expects.
+#[ink::contract]
+pub mod payout_receiver {
+    use hackathonia::hackathon_escrow::ContractError;
+    use hackathonia::{Escrow, PayoutReceiver};
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::primitives::U256;
+
+    /// How the receiver answers payout notifications
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout, Debug))]
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub enum Response {
+        Accept,
+        Reject,
+        /// Try to sign the given payout request from inside the notification, then accept
+        Reenter(u32),
+    }
+
+    /// Payout notification as received
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout, Debug))]
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Notification {
+        pub hackathon_id: u32,
+        pub request_id: u32,
+        pub amount: U256,
+        pub reason: String,
+    }
+
+    #[ink(storage)]
+    pub struct PayoutRecorder {
+        /// Escrow contract the payouts come from
+        escrow: Address,
+        response: Response,
+        /// Accepted notifications, oldest first
+        notifications: Vec<Notification>,
+        /// Result of the last call back into the escrow
+        reentry_result: Option<Result<(), ContractError>>,
+    }
+
+    impl PayoutRecorder {
+        #[ink(constructor)]
+        pub fn new(escrow: Address, response: Response) -> Self {
+            Self {
+                escrow,
+                response,
+                notifications: Vec::new(),
+                reentry_result: None,
+            }
+        }
+
+        /// Change how the next notifications are answered
+        #[ink(message)]
+        pub fn set_response(&mut self, response: Response) {
+            self.response = response;
+        }
+
+        /// Get the accepted notifications, oldest first
+        #[ink(message)]
+        pub fn get_notifications(&self) -> Vec<Notification> {
+            self.notifications.clone()
+        }
+
+        /// Get the result of the last call back into the escrow, if one was made
+        #[ink(message)]
+        pub fn get_reentry_result(&self) -> Option<Result<(), ContractError>> {
+            self.reentry_result
+        }
+    }
+
+    impl PayoutReceiver for PayoutRecorder {
+        #[ink(message)]
+        fn on_payout_received(&mut self, hackathon_id: u32, request_id: u32, amount: U256, reason: String) -> bool {
+            match self.response {
+                Response::Accept => {}
+                Response::Reject => return false,
+                Response::Reenter(reentry_request_id) => {
+                    let mut escrow: ink::contract_ref_from_path!(Escrow) = self.escrow.into();
+                    self.reentry_result = Some(escrow.sign_payout(reentry_request_id));
+                }
+            }
+            self.notifications.push(Notification {
+                hackathon_id,
+                request_id,
+                amount,
+                reason,
+            });
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use hackathonia::hackathon_escrow::{ActivityType, HackathonEscrowContractRef, PayoutCallbackPolicy};
+        use ink::env::test;
+        use ink::env::DefaultEnvironment as Env;
+        use ink::ToAddr;
+
+        const HACKATHON_ID: u32 = 1;
+
+        /// Deploy a funded escrow organized by Alice with Bob as the only signer, and a
+        /// recorder answering with `response`
+        fn deploy(response: Response) -> (HackathonEscrowContractRef, PayoutRecorderRef) {
+            let accounts = test::default_accounts();
+            let escrow_code = test::upload_code::<Env, HackathonEscrowContractRef>();
+            let recorder_code = test::upload_code::<Env, PayoutRecorderRef>();
+            test::set_caller(accounts.alice);
+
+            test::set_value_transferred(U256::from(1000u32));
+            let escrow = HackathonEscrowContractRef::new_dedicated(
+                accounts.alice,
+                accounts.alice,
+                String::from("Hack"),
+                String::new(),
+                vec![accounts.bob],
+                1,
+            )
+            .code_hash(escrow_code)
+            .salt_bytes(Some([1; 32]))
+            .instantiate()
+            .unwrap();
+            test::set_value_transferred(U256::from(0u32));
+            test::set_contract_balance(escrow.to_addr(), U256::from(1000u32));
+
+            let recorder = PayoutRecorderRef::new(escrow.to_addr(), response)
+                .code_hash(recorder_code)
+                .salt_bytes(Some([2; 32]))
+                .instantiate();
+            (escrow, recorder)
+        }
+
+        /// Request a payout to the recorder as Bob and sign it, which executes it
+        fn pay(escrow: &mut HackathonEscrowContractRef, recorder: &PayoutRecorderRef) -> (u32, Result<(), ContractError>) {
+            test::set_caller(test::default_accounts().bob);
+            let request_id = escrow
+                .request_payout(HACKATHON_ID, recorder.to_addr(), U256::from(300u32), String::from("Winner"))
+                .unwrap();
+            (request_id, escrow.sign_payout(request_id))
+        }
+
+        #[ink::test]
+        fn accepted_notifications_carry_the_payout() {
+            let (mut escrow, recorder) = deploy(Response::Accept);
+
+            let (request_id, result) = pay(&mut escrow, &recorder);
+            assert_eq!(result, Ok(()));
+            assert!(escrow.get_payout_request(request_id).unwrap().is_executed);
+            assert_eq!(
+                recorder.get_notifications(),
+                vec![Notification {
+                    hackathon_id: HACKATHON_ID,
+                    request_id,
+                    amount: U256::from(300u32),
+                    reason: String::from("Winner"),
+                }]
+            );
+        }
+
+        #[ink::test]
+        fn rejected_notifications_follow_the_hackathon_policy() {
+            let (mut escrow, recorder) = deploy(Response::Reject);
+
+            // Proceed: the payout stands and the failure is logged
+            let (request_id, result) = pay(&mut escrow, &recorder);
+            assert_eq!(result, Ok(()));
+            assert!(escrow.get_payout_request(request_id).unwrap().is_executed);
+            let latest = escrow.get_recent_activity(HACKATHON_ID, 0, 1).unwrap().items.remove(0);
+            assert_eq!(latest.activity_type, ActivityType::PayoutCallbackFailed);
+            assert_eq!((latest.id, latest.target), (request_id, Some(recorder.to_addr())));
+
+            // Revert: the payout fails with the callback
+            test::set_caller(test::default_accounts().alice);
+            escrow.set_payout_callback_policy(HACKATHON_ID, PayoutCallbackPolicy::Revert).unwrap();
+            let (_, result) = pay(&mut escrow, &recorder);
+            assert_eq!(result, Err(ContractError::PayoutCallbackFailed));
+            assert!(recorder.get_notifications().is_empty());
+        }
+
+        #[ink::test]
+        fn receivers_cannot_reenter_the_escrow() {
+            let (mut escrow, mut recorder) = deploy(Response::Accept);
+
+            // The recorder tries to sign a second, still pending request while notified
+            test::set_caller(test::default_accounts().bob);
+            let pending_id = escrow
+                .request_payout(HACKATHON_ID, recorder.to_addr(), U256::from(200u32), String::new())
+                .unwrap();
+            recorder.set_response(Response::Reenter(pending_id));
+
+            let (request_id, result) = pay(&mut escrow, &recorder);
+            assert_eq!(result, Ok(()));
+            assert!(escrow.get_payout_request(request_id).unwrap().is_executed);
+            assert_eq!(recorder.get_reentry_result(), Some(Err(ContractError::ReentrantCall)));
+            assert_eq!(recorder.get_notifications().len(), 1);
+            assert!(escrow.get_payout_request(pending_id).unwrap().signatures.is_empty());
+
+            // The guard is released once the notification returns
+            recorder.set_response(Response::Accept);
+            test::set_caller(test::default_accounts().bob);
+            assert_eq!(escrow.sign_payout(pending_id), Ok(()));
+            assert!(escrow.get_payout_request(pending_id).unwrap().is_executed);
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use hackathonia::hackathon_escrow::{HackathonEscrowContract, HackathonEscrowContractRef, PayoutCallbackPolicy};
+        use ink::primitives::AccountId;
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn rejected_payouts_revert_until_the_policy_changes<Client: E2EBackend>(
+            mut client: Client,
+        ) -> E2EResult<()> {
+            // given: a hackathon with Bob as its only signer paying a rejecting recorder
+            let mut constructor = HackathonEscrowContractRef::new();
+            let escrow = client
+                .instantiate("hackathonia", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("escrow instantiation failed");
+            let mut call_builder = escrow.call_builder::<HackathonEscrowContract>();
+
+            let bob = ink_e2e::address_from_keypair::<AccountId>(&ink_e2e::bob());
+            let create = call_builder.create_hackathon(String::from("Hack"), String::new(), vec![bob], 1);
+            let hackathon_id = client
+                .call(&ink_e2e::alice(), &create)
+                .value(1_000_000_000_000u128)
+                .submit()
+                .await
+                .expect("create_hackathon failed")
+                .return_value()
+                .expect("create_hackathon rejected");
+
+            let mut constructor = PayoutRecorderRef::new(escrow.addr, Response::Reject);
+            let recorder = client
+                .instantiate("hackathonia_payout_receiver", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("recorder instantiation failed");
+
+            let revert = call_builder.set_payout_callback_policy(hackathon_id, PayoutCallbackPolicy::Revert);
+            client.call(&ink_e2e::alice(), &revert).submit().await.expect("set policy failed");
+            let request = call_builder.request_payout(hackathon_id, recorder.addr, U256::from(1u32), String::new());
+            let request_id = client
+                .call(&ink_e2e::bob(), &request)
+                .submit()
+                .await
+                .expect("request_payout failed")
+                .return_value()
+                .expect("request_payout rejected");
+
+            // when: the final signature executes the payout and the recorder rejects it
+            let sign = call_builder.sign_payout(request_id);
+            let rejected = client.call(&ink_e2e::bob(), &sign).submit().await.expect("sign failed");
+            assert_eq!(rejected.return_value(), Err(ContractError::PayoutCallbackFailed));
+
+            // then: the whole approval reverts, so it can be retried once the policy changes
+            let get_request = call_builder.get_payout_request(request_id);
+            let pending = client
+                .call(&ink_e2e::bob(), &get_request)
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("request missing");
+            assert!(!pending.is_executed && pending.signatures.is_empty());
+
+            let proceed = call_builder.set_payout_callback_policy(hackathon_id, PayoutCallbackPolicy::Proceed);
+            client.call(&ink_e2e::alice(), &proceed).submit().await.expect("set policy failed");
+            let retried = client.call(&ink_e2e::bob(), &sign).submit().await.expect("sign failed");
+            assert_eq!(retried.return_value(), Ok(()));
+            let executed = client
+                .call(&ink_e2e::bob(), &get_request)
+                .dry_run()
+                .await?
+                .return_value()
+                .expect("request missing");
+            assert!(executed.is_executed);
+
+            Ok(())
+        }
+    }
+}